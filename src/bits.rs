@@ -0,0 +1,64 @@
+use proc_macro::{Spacing, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{grid, parse_options, NumTokenParseError};
+
+/// The number of bits of a type, or `None` for the pointer-sized types, whose
+/// size depends on the target and not on the host the macro runs on
+const fn bit_count(int_type: IntType) -> Option<u32> {
+    match int_type {
+        IntType::I8 | IntType::U8 => Some(8),
+        IntType::I16 | IntType::U16 => Some(16),
+        IntType::I32 | IntType::U32 => Some(32),
+        IntType::I64 | IntType::U64 => Some(64),
+        IntType::I128 | IntType::U128 => Some(128),
+        IntType::Isize | IntType::Usize => None,
+    }
+}
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let mut tokens: Vec<TokenTree> = token_stream.into_iter().collect();
+
+    // The bit order is an option of its own, which has to come first
+    let lsb_first = matches!(
+        &tokens[..],
+        [TokenTree::Ident(ident), TokenTree::Punct(punct), ..]
+            if ident.to_string() == "lsb"
+                && punct.as_char() == ':'
+                && punct.spacing() == Spacing::Alone
+    );
+    if lsb_first {
+        tokens.drain(..2);
+    }
+
+    let (options, tokens) = parse_options(tokens.into_iter().collect())?;
+    let (sign, value) = grid::evaluate_cell(tokens, options)?;
+    let int_type = match options.int_type {
+        Some(int_type) if int_type.contains(value) => int_type,
+        Some(_) => return Err(NumTokenParseError::OutOfRange),
+        None => IntType::for_value(matches!(sign, Sign::Positive), value)
+            .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+    let bits = bit_count(int_type).ok_or(NumTokenParseError::PointerSizedBits)?;
+
+    // Negative values are written in two's complement, which the cast keeps
+    #[allow(clippy::cast_sign_loss)]
+    let pattern = value as u128;
+    let mut elements: Vec<&str> = (0..bits)
+        .map(|bit| {
+            if pattern >> bit & 1 == 1 {
+                "true"
+            } else {
+                "false"
+            }
+        })
+        .collect();
+    if !lsb_first {
+        elements.reverse();
+    }
+
+    Ok(format!("[{}]", elements.join(", "))
+        .parse()
+        .expect("Failed to output bit array"))
+}
@@ -0,0 +1,58 @@
+use proc_macro::{Literal, Span, TokenStream, TokenTree};
+
+use word_nums_core::IntType;
+
+use crate::NumTokenParseError;
+
+/// Which end of the value range of a type to emit
+#[derive(Debug, Copy, Clone)]
+pub enum Bound {
+    Min,
+    Max,
+}
+
+fn bound_literal(int_type: IntType, bound: Bound) -> Option<Literal> {
+    let max = matches!(bound, Bound::Max);
+    Some(match int_type {
+        IntType::I8 => Literal::i8_suffixed(if max { i8::MAX } else { i8::MIN }),
+        IntType::I16 => Literal::i16_suffixed(if max { i16::MAX } else { i16::MIN }),
+        IntType::I32 => Literal::i32_suffixed(if max { i32::MAX } else { i32::MIN }),
+        IntType::I64 => Literal::i64_suffixed(if max { i64::MAX } else { i64::MIN }),
+        IntType::I128 => Literal::i128_suffixed(if max { i128::MAX } else { i128::MIN }),
+        IntType::U8 => Literal::u8_suffixed(if max { u8::MAX } else { 0 }),
+        IntType::U16 => Literal::u16_suffixed(if max { u16::MAX } else { 0 }),
+        IntType::U32 => Literal::u32_suffixed(if max { u32::MAX } else { 0 }),
+        IntType::U64 => Literal::u64_suffixed(if max { u64::MAX } else { 0 }),
+        IntType::U128 => Literal::u128_suffixed(if max { u128::MAX } else { 0 }),
+        IntType::Isize | IntType::Usize => return None,
+    })
+}
+
+/// Reads the name of a primitive integer type, which has to be the only token
+pub fn parse_type(tokens: &[TokenTree]) -> Result<IntType, NumTokenParseError> {
+    let ident = match tokens {
+        [TokenTree::Ident(ident)] => ident,
+        [] => return Err(NumTokenParseError::MissingType(Span::call_site())),
+        [token] | [_, token, ..] => return Err(NumTokenParseError::MissingType(token.span())),
+    };
+    IntType::from_name(&ident.to_string())
+        .ok_or_else(|| NumTokenParseError::UnknownType(ident.clone()))
+}
+
+pub fn expand(token_stream: TokenStream, bound: Bound) -> Result<TokenStream, NumTokenParseError> {
+    let tokens: Vec<TokenTree> = token_stream.into_iter().collect();
+    let int_type = parse_type(&tokens)?;
+
+    // The size of the pointer-sized types depends on the target, which is not
+    // necessarily the host the macro runs on, so their constants are used
+    if let Some(literal) = bound_literal(int_type, bound) {
+        return Ok(TokenTree::Literal(literal).into());
+    }
+    let name = match bound {
+        Bound::Min => "MIN",
+        Bound::Max => "MAX",
+    };
+    Ok(format!("::core::primitive::{}::{name}", int_type.name())
+        .parse()
+        .expect("Failed to output type bound"))
+}
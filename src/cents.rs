@@ -0,0 +1,53 @@
+use proc_macro::{Span, TokenStream, TokenTree};
+use word_nums_core::{Number, ParseError, Sign};
+
+use crate::{grid, make_literal, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, mut tokens) = parse_options(token_stream)?;
+
+    // Only the last "and" separates the cents, so that the whole part can still
+    // be written like "one hundred and five"
+    let cents = match tokens.iter().rposition(is_and) {
+        Some(position) => {
+            let cents_tokens = tokens.split_off(position + 1);
+            tokens.pop();
+
+            let span = cents_tokens
+                .first()
+                .map_or_else(Span::call_site, TokenTree::span);
+            match grid::evaluate_cell(cents_tokens, options)? {
+                (Sign::Unspecified, cents) if (0..100).contains(&cents) => cents,
+                _ => return Err(NumTokenParseError::InvalidCents(span)),
+            }
+        }
+        None => 0,
+    };
+
+    let (sign, whole) = grid::evaluate_cell(tokens, options)?;
+    let value = whole
+        .checked_mul(100)
+        .and_then(|x| {
+            if matches!(sign, Sign::Negative) {
+                x.checked_sub(cents)
+            } else {
+                x.checked_add(cents)
+            }
+        })
+        .ok_or(NumTokenParseError::Parse(
+            ParseError::Overflow,
+            Span::call_site(),
+        ))?;
+
+    let parsed = Number {
+        sign,
+        value,
+        fraction: None,
+    };
+    let literal = make_literal(crate::Number { options, parsed })?;
+    Ok(TokenTree::Literal(literal).into())
+}
+
+fn is_and(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string().eq_ignore_ascii_case("and"))
+}
@@ -0,0 +1,45 @@
+use proc_macro::{Delimiter, Group, Literal, Punct, Spacing, TokenStream, TokenTree};
+
+use word_nums_core::Sign;
+
+use crate::{evaluate_tokens, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // Every word is a digit of its own, so "twenty" or "hundred" are rejected
+    let mut elements_stream = TokenStream::new();
+    for token in tokens {
+        if matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '-') {
+            continue;
+        }
+
+        let span = token.span();
+        // A lone sign is no digit either
+        let number = match evaluate_tokens(vec![token], options) {
+            Err(NumTokenParseError::EmptyNumber) => {
+                return Err(NumTokenParseError::InvalidDigit(span));
+            }
+            result => result?,
+        };
+        let digit = u32::try_from(number.parsed.value)
+            .ok()
+            .and_then(|x| char::from_digit(x, 10))
+            .filter(|_| {
+                number.parsed.fraction.is_none() && matches!(number.parsed.sign, Sign::Unspecified)
+            })
+            .ok_or(NumTokenParseError::InvalidDigit(span))?;
+        elements_stream.extend([
+            TokenTree::Literal(Literal::character(digit)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    if elements_stream.is_empty() {
+        return Err(NumTokenParseError::EmptyNumber);
+    }
+    Ok(TokenTree::Group(Group::new(Delimiter::Bracket, elements_stream)).into())
+}
@@ -0,0 +1,70 @@
+use proc_macro::{TokenStream, TokenTree};
+
+use word_nums_core::IntType;
+
+use crate::{grid, int_type, parse_options, NumTokenParseError, NumType, Options};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, mut tokens) = parse_options(token_stream)?;
+    let int_type = options.int_type.unwrap_or(IntType::I32);
+
+    // The imaginary part is the one that ends in the imaginary unit
+    let is_imaginary = tokens.last().is_some_and(|x| is_word(x, "i"));
+    if is_imaginary {
+        tokens.pop();
+    }
+
+    // A "plus" or "minus" after the start of the number separates the real
+    // part from the imaginary one, anywhere else it is a sign
+    let operator = tokens
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, x)| is_word(x, "plus") || is_word(x, "minus"))
+        .map(|(position, x)| (position, is_word(x, "minus")));
+
+    let (real, imaginary) = match operator {
+        Some((position, is_minus)) if is_imaginary => {
+            let imaginary_tokens = tokens.split_off(position + 1);
+            tokens.pop();
+            let imaginary = evaluate_imaginary(imaginary_tokens, options)?;
+            let real = grid::evaluate_cell(tokens, options)?.1;
+            let imaginary = if is_minus {
+                imaginary
+                    .checked_neg()
+                    .ok_or(NumTokenParseError::OutOfRange)?
+            } else {
+                imaginary
+            };
+            (real, imaginary)
+        }
+        _ if is_imaginary => (0, evaluate_imaginary(tokens, options)?),
+        _ => (grid::evaluate_cell(tokens, options)?.1, 0),
+    };
+
+    let real = int_type::int_literal(int_type, real).ok_or(NumTokenParseError::OutOfRange)?;
+    let imaginary =
+        int_type::int_literal(int_type, imaginary).ok_or(NumTokenParseError::OutOfRange)?;
+    Ok(format!(
+        "::num_complex::Complex::<{}>::new({real}, {imaginary})",
+        int_type.name()
+    )
+    .parse()
+    .expect("Failed to output complex number"))
+}
+
+/// Evaluates the factor of the imaginary unit, which may be left out for
+/// just "i"
+fn evaluate_imaginary(
+    tokens: Vec<TokenTree>,
+    options: Options,
+) -> Result<NumType, NumTokenParseError> {
+    if tokens.is_empty() {
+        return Ok(1);
+    }
+    Ok(grid::evaluate_cell(tokens, options)?.1)
+}
+
+fn is_word(token: &TokenTree, word: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string() == word)
+}
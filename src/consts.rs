@@ -0,0 +1,83 @@
+use proc_macro::{Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use word_nums_core::{IntType, Sign};
+
+use crate::{evaluate_tokens, grid, make_literal, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let mut out = TokenStream::new();
+    for item in grid::split_punct(token_stream.into_iter().collect(), ';') {
+        if !item.is_empty() {
+            out.extend(expand_item(item)?);
+        }
+    }
+    Ok(out)
+}
+
+/// Expands a single item like `pub FOO: u8 = forty two` into a const item
+fn expand_item(mut item: Vec<TokenTree>) -> Result<TokenStream, NumTokenParseError> {
+    let span = item.first().map_or_else(Span::call_site, TokenTree::span);
+    let Some(eq_position) = item
+        .iter()
+        .position(|x| matches!(x, TokenTree::Punct(punct) if punct.as_char() == '='))
+    else {
+        return Err(NumTokenParseError::InvalidConst(span));
+    };
+
+    let words: TokenStream = item.drain(eq_position..).skip(1).collect();
+    let (mut options, tokens) = parse_options(words)?;
+
+    // The name may be followed by an explicit type, anything before it like
+    // the visibility is passed through
+    let explicit_type = match &item[..] {
+        [.., TokenTree::Ident(_), TokenTree::Punct(punct), TokenTree::Ident(type_name)]
+            if punct.as_char() == ':' =>
+        {
+            let int_type = IntType::from_name(&type_name.to_string())
+                .ok_or_else(|| NumTokenParseError::UnknownType(type_name.clone()))?;
+            item.truncate(item.len() - 2);
+            Some(int_type)
+        }
+        [.., TokenTree::Ident(_)] => None,
+        _ => return Err(NumTokenParseError::InvalidConst(span)),
+    };
+    let Some(TokenTree::Ident(name)) = item.pop() else {
+        return Err(NumTokenParseError::InvalidConst(span));
+    };
+
+    if explicit_type.is_some() {
+        if options.int_type.is_some() {
+            return Err(NumTokenParseError::UnexpectedIntType);
+        }
+        options.int_type = explicit_type;
+    }
+
+    let mut number = evaluate_tokens(tokens, options)?;
+    let type_name = if number.parsed.fraction.is_some() && options.int_type.is_none() {
+        "f64"
+    } else {
+        let int_type = options
+            .int_type
+            .or_else(|| {
+                IntType::smallest_fit(
+                    matches!(number.parsed.sign, Sign::Positive),
+                    std::iter::once(number.parsed.value),
+                )
+            })
+            .ok_or(NumTokenParseError::OutOfRange)?;
+        number.options.int_type = Some(int_type);
+        int_type.name()
+    };
+    let literal = make_literal(number)?;
+
+    let mut out: TokenStream = item.into_iter().collect();
+    out.extend([
+        TokenTree::Ident(Ident::new("const", name.span())),
+        TokenTree::Ident(name.clone()),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new(type_name, name.span())),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+        TokenTree::Literal(literal),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]);
+    Ok(out)
+}
@@ -0,0 +1,65 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::IntType;
+
+use crate::{grid, int_type, parse_options, NumTokenParseError, NumType};
+
+/// The type, the valid range and the range error of the year, the month and
+/// the day
+const FIELDS: [(IntType, NumType, NumType, &str); 3] = [
+    (
+        IntType::U16,
+        0,
+        u16::MAX as NumType,
+        "The year must be between zero and 65535",
+    ),
+    (
+        IntType::U8,
+        1,
+        12,
+        "The month must be between one and twelve",
+    ),
+    (
+        IntType::U8,
+        1,
+        31,
+        "The day must be between one and thirty one",
+    ),
+];
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // The year, the month and the day are separated by semicolons
+    let components = grid::split_punct(tokens, ';');
+    if components.len() != FIELDS.len() {
+        let span = components
+            .get(FIELDS.len())
+            .and_then(|x| x.first())
+            .map_or_else(Span::call_site, TokenTree::span);
+        return Err(NumTokenParseError::InvalidDate(span));
+    }
+
+    let mut elements_stream = TokenStream::new();
+    for (component, (int_type, min, max, message)) in components.into_iter().zip(FIELDS) {
+        let span = component
+            .first()
+            .map_or_else(Span::call_site, TokenTree::span);
+        let (_, value) = grid::evaluate_cell(component, options)?;
+        if !(min..=max).contains(&value) {
+            return Err(NumTokenParseError::DateOutOfRange(span, message));
+        }
+
+        let literal =
+            int_type::int_literal(int_type, value).ok_or(NumTokenParseError::OutOfRange)?;
+        elements_stream.extend([
+            TokenTree::Literal(literal),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Parenthesis, elements_stream)).into())
+}
@@ -0,0 +1,19 @@
+use proc_macro::TokenStream;
+
+use crate::{evaluate, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let number = evaluate(token_stream)?;
+    if number.options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // The decimal string is exact, so the value never passes through a float
+    let digits = number.parsed.to_string();
+    Ok(format!(
+        "<::bigdecimal::BigDecimal as ::core::str::FromStr>::from_str({digits:?})\
+         .expect(\"Failed to construct a BigDecimal\")"
+    )
+    .parse()
+    .expect("Failed to output BigDecimal"))
+}
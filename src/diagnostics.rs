@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use proc_macro::{Level, Span};
+use word_nums_core::{Language, Token};
+
+use crate::Options;
+
+/// Whether the scale note was already emitted for the crate being compiled
+static SCALE_NOTED: AtomicBool = AtomicBool::new(false);
+
+/// Notes that "billion" and the larger multipliers differ by region, unless
+/// the scale was given explicitly. The note is only emitted once, since it would
+/// otherwise be repeated for every number.
+pub fn note_scale(texts: &[(Span, String, bool)], tokens: &[Token], options: Options) {
+    if options.explicit_scale || options.parse.language != Language::English {
+        return;
+    }
+    let Some((index, value)) = word_nums_core::largest_multiplier(tokens, options.parse) else {
+        return;
+    };
+    if value < 1_000_000_000 || SCALE_NOTED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+
+    let (span, word, _) = &texts[index];
+    proc_macro::Diagnostic::spanned(
+        *span,
+        Level::Note,
+        format!("'{word}' is read in the short scale, where a billion is a thousand millions"),
+    )
+    .help("Use the 'short:' or 'long:' option to choose the scale explicitly")
+    .emit();
+}
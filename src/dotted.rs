@@ -0,0 +1,63 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{grid, int_type, parse_options, NumTokenParseError};
+
+fn is_separator(token: &TokenTree) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.as_char() == '.',
+        TokenTree::Ident(ident) => ident.to_string().eq_ignore_ascii_case("dot"),
+        _ => false,
+    }
+}
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+
+    let mut components = vec![Vec::new()];
+    for token in tokens {
+        if is_separator(&token) {
+            components.push(Vec::new());
+        } else {
+            components
+                .last_mut()
+                .expect("There is always at least one component")
+                .push(token);
+        }
+    }
+    let values = components
+        .into_iter()
+        .map(|component| {
+            let span = component.first().map(TokenTree::span);
+            match grid::evaluate_cell(component, options)? {
+                (Sign::Negative, value) if value != 0 => {
+                    Err(NumTokenParseError::NegativeComponent(
+                        span.expect("The component is not empty"),
+                    ))
+                }
+                (_, value) => Ok(value),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The components are never negative, even with a signed type option, so
+    // the inferred type is always unsigned
+    let int_type = match options.int_type {
+        Some(int_type) => int_type,
+        None => IntType::smallest_fit(true, values.iter().copied())
+            .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+
+    let mut elements_stream = TokenStream::new();
+    for value in values {
+        let literal = int_type::radix_literal(int_type, value, options.radix)
+            .ok_or(NumTokenParseError::OutOfRange)?;
+        elements_stream.extend([
+            TokenTree::Literal(literal),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Bracket, elements_stream)).into())
+}
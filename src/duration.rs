@@ -0,0 +1,80 @@
+use proc_macro::{Span, TokenStream, TokenTree};
+
+use crate::{evaluate_tokens, parse_options, NumTokenParseError, NumType};
+
+const NANOS_PER_SEC: NumType = 1_000_000_000;
+
+/// The length of a time unit in nanoseconds
+fn unit_nanos(word: &str) -> Option<NumType> {
+    match word.to_ascii_lowercase().as_str() {
+        "nanosecond" | "nanoseconds" => Some(1),
+        "microsecond" | "microseconds" => Some(1000),
+        "millisecond" | "milliseconds" => Some(1_000_000),
+        "second" | "seconds" => Some(NANOS_PER_SEC),
+        "minute" | "minutes" => Some(60 * NANOS_PER_SEC),
+        "hour" | "hours" => Some(60 * 60 * NANOS_PER_SEC),
+        "day" | "days" => Some(24 * 60 * 60 * NANOS_PER_SEC),
+        "week" | "weeks" => Some(7 * 24 * 60 * 60 * NANOS_PER_SEC),
+        _ => None,
+    }
+}
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // Every segment is a number followed by its unit, like "two hours"
+    let mut units = Vec::new();
+    let mut segment = Vec::new();
+    let mut total: NumType = 0;
+    for token in tokens {
+        let nanos = match &token {
+            TokenTree::Ident(ident) => unit_nanos(&ident.to_string()),
+            _ => None,
+        };
+        let Some(nanos) = nanos else {
+            segment.push(token);
+            continue;
+        };
+
+        // The units can be given in any order, but only once each
+        if units.contains(&nanos) {
+            return Err(NumTokenParseError::DuplicateUnit(token.span()));
+        }
+        units.push(nanos);
+
+        if segment.is_empty() {
+            return Err(NumTokenParseError::EmptyNumber);
+        }
+        let number = evaluate_tokens(std::mem::take(&mut segment), options)?;
+        if number.parsed.fraction.is_some() {
+            return Err(NumTokenParseError::FractionalInteger);
+        }
+        total = number
+            .parsed
+            .value
+            .checked_mul(nanos)
+            .and_then(|x| total.checked_add(x))
+            .ok_or(NumTokenParseError::OutOfRange)?;
+    }
+
+    if let Some(token) = segment.first() {
+        return Err(NumTokenParseError::MissingUnit(token.span()));
+    }
+    if units.is_empty() {
+        return Err(NumTokenParseError::MissingUnit(Span::call_site()));
+    }
+    if total < 0 {
+        return Err(NumTokenParseError::NegativeDuration);
+    }
+
+    let secs = u64::try_from(total / NANOS_PER_SEC).map_err(|_| NumTokenParseError::OutOfRange)?;
+    let nanos = total % NANOS_PER_SEC;
+    Ok(
+        format!("::core::time::Duration::new({secs}u64, {nanos}u32)")
+            .parse()
+            .expect("Failed to output duration"),
+    )
+}
@@ -0,0 +1,93 @@
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::{grid, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let mut item: Vec<TokenTree> = token_stream.into_iter().collect();
+
+    // Anything before the name like attributes and the visibility is passed
+    // through, the variants are given in braces after it
+    let (name, variants) = match (item.pop(), item.pop()) {
+        (Some(TokenTree::Group(group)), Some(TokenTree::Ident(name)))
+            if group.delimiter() == Delimiter::Brace =>
+        {
+            (name, group)
+        }
+        (Some(token), _) => return Err(NumTokenParseError::InvalidEnum(token.span())),
+        (None, _) => return Err(NumTokenParseError::InvalidEnum(Span::call_site())),
+    };
+
+    // The enum keyword is optional
+    if matches!(item.last(), Some(TokenTree::Ident(ident)) if ident.to_string() == "enum") {
+        item.pop();
+    }
+
+    let (options, tokens) = parse_options(variants.stream())?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+    let mut body = TokenStream::new();
+    for words in grid::split_punct(tokens, ',') {
+        let Some(span) = words.first().map(TokenTree::span) else {
+            return Err(NumTokenParseError::InvalidEnum(variants.span_close()));
+        };
+
+        // The words of the number make up the name, like "TwentyOne"
+        let variant_name: String = words
+            .iter()
+            .filter_map(|token| match token {
+                TokenTree::Ident(ident) => Some(capitalize(&ident.to_string())),
+                _ => None,
+            })
+            .collect();
+        if !is_valid_name(&variant_name) {
+            return Err(NumTokenParseError::InvalidVariant(span));
+        }
+        let (_, value) = grid::evaluate_cell(words, options)?;
+
+        if names.contains(&variant_name) {
+            return Err(NumTokenParseError::DuplicateVariant(span));
+        }
+        if values.contains(&value) {
+            return Err(NumTokenParseError::DuplicateDiscriminant(span));
+        }
+
+        body.extend([
+            TokenTree::Ident(Ident::new(&variant_name, span)),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Literal(Literal::i128_unsuffixed(value)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+        names.push(variant_name);
+        values.push(value);
+    }
+
+    let mut out: TokenStream = item.into_iter().collect();
+    out.extend([
+        TokenTree::Ident(Ident::new("enum", name.span())),
+        TokenTree::Ident(name),
+        TokenTree::Group(Group::new(Delimiter::Brace, body)),
+    ]);
+    Ok(out)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect()
+    })
+}
+
+/// Whether the name can be used for a variant. Only "Self" is a keyword that
+/// starts with a capital letter.
+fn is_valid_name(name: &str) -> bool {
+    name != "Self"
+        && name.starts_with(char::is_alphabetic)
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
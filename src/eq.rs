@@ -0,0 +1,40 @@
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree};
+
+use word_nums_core::ParseError;
+
+use crate::{grid, parse_options, percent, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    // Each side takes its own options, so that languages can be compared
+    let sides = grid::split_punct(token_stream.into_iter().collect(), ';');
+    let [left, right] = &sides[..] else {
+        return Err(NumTokenParseError::InvalidEq(Span::call_site()));
+    };
+    let span = right.first().map_or_else(Span::call_site, TokenTree::span);
+
+    let (left_text, left) = evaluate_side(left.clone())?;
+    let (right_text, right) = evaluate_side(right.clone())?;
+
+    // Fractions are compared by cross multiplying, so "a half" equals
+    // "zero point five"
+    let overflow = || NumTokenParseError::Parse(ParseError::Overflow, Span::call_site());
+    let left_cross = left.0.checked_mul(right.1).ok_or_else(overflow)?;
+    let right_cross = right.0.checked_mul(left.1).ok_or_else(overflow)?;
+    if left_cross != right_cross {
+        return Err(NumTokenParseError::NotEqual(span, left_text, right_text));
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())).into())
+}
+
+/// Evaluates one side into its text and its value as a fraction
+fn evaluate_side(tokens: Vec<TokenTree>) -> Result<(String, (i128, i128)), NumTokenParseError> {
+    let (options, tokens) = parse_options(tokens.into_iter().collect())?;
+    let text = tokens
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let (_, numerator, denominator) = percent::evaluate_rational(tokens, options)?;
+    Ok((text, (numerator, denominator)))
+}
@@ -0,0 +1,91 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{evaluate_tokens, int_type, parse_options, NumTokenParseError, NumType, Options};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+
+    let mut rows = Vec::new();
+    for row in split_punct(tokens, ';') {
+        let span = row.first().map_or_else(Span::call_site, TokenTree::span);
+        let cells = split_punct(row, ',')
+            .into_iter()
+            .map(|cell| evaluate_cell(cell, options))
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.push((span, cells));
+    }
+
+    // All rows need to have the same number of columns as the first one
+    let columns = rows.first().map_or(0, |(_, cells)| cells.len());
+    if let Some((span, _)) = rows.iter().find(|(_, cells)| cells.len() != columns) {
+        return Err(NumTokenParseError::RaggedRow(*span));
+    }
+
+    let int_type = match options.int_type {
+        Some(int_type) => int_type,
+        None => IntType::smallest_fit(
+            rows.iter()
+                .flat_map(|(_, cells)| cells)
+                .all(|(sign, _)| matches!(sign, Sign::Positive)),
+            rows.iter().flat_map(|(_, cells)| cells).map(|(_, x)| *x),
+        )
+        .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+
+    let mut rows_stream = TokenStream::new();
+    for (_, cells) in rows {
+        let mut cells_stream = TokenStream::new();
+        for (_, value) in cells {
+            let literal = int_type::radix_literal(int_type, value, options.radix)
+                .ok_or(NumTokenParseError::OutOfRange)?;
+            cells_stream.extend([
+                TokenTree::Literal(literal),
+                TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            ]);
+        }
+
+        rows_stream.extend([
+            TokenTree::Group(Group::new(Delimiter::Bracket, cells_stream)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Bracket, rows_stream)).into())
+}
+
+pub fn evaluate_cell(
+    tokens: Vec<TokenTree>,
+    options: Options,
+) -> Result<(Sign, NumType), NumTokenParseError> {
+    if tokens.is_empty() {
+        return Err(NumTokenParseError::EmptyNumber);
+    }
+
+    let number = evaluate_tokens(tokens, options)?.parsed;
+    if number.fraction.is_some() {
+        return Err(NumTokenParseError::FractionalInteger);
+    }
+    Ok((number.sign, number.value))
+}
+
+/// Splits the tokens at every occurrence of the given punctuation character.
+/// A single trailing separator is allowed.
+pub fn split_punct(tokens: Vec<TokenTree>, separator: char) -> Vec<Vec<TokenTree>> {
+    let mut groups = vec![Vec::new()];
+    for token in tokens {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == separator => groups.push(Vec::new()),
+            _ => groups
+                .last_mut()
+                .expect("There is always at least one group")
+                .push(token),
+        }
+    }
+
+    if groups.len() > 1 && groups.last().is_some_and(Vec::is_empty) {
+        groups.pop();
+    }
+    groups
+}
@@ -0,0 +1,76 @@
+use proc_macro::TokenStream;
+
+use word_nums_core::Number;
+
+use crate::{evaluate, NumTokenParseError};
+
+/// The number of significant bits of an `f16`, including the implicit one
+const SIGNIFICANT_BITS: u32 = 11;
+
+/// The exponent of the smallest subnormal `f16`, which is `2^-24`
+const MIN_EXPONENT: i64 = -24;
+
+/// The largest finite `f16`
+const MAX: u128 = 65504;
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let number = evaluate(token_stream)?;
+    if number.options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+    check_exact(number.parsed)?;
+
+    // The digits are exact, so the literal has exactly the checked value
+    Ok(format!("{}f16", number.parsed)
+        .parse()
+        .expect("Failed to output f16 literal"))
+}
+
+/// Checks that the magnitude of the number is an `f16` without any rounding.
+///
+/// The number is the exact fraction `numerator / denominator`. It is an `f16`
+/// if the reduced denominator is a power of two, and the odd part of the
+/// numerator fits into the significand at an exponent that is not too small.
+fn check_exact(number: Number) -> Result<(), NumTokenParseError> {
+    let (numerator, denominator) = match number.fraction {
+        Some(fraction) => (
+            number
+                .value
+                .unsigned_abs()
+                .checked_mul(fraction.denominator.unsigned_abs())
+                .and_then(|x| x.checked_add(fraction.numerator.unsigned_abs()))
+                .ok_or(NumTokenParseError::OutOfRange)?,
+            fraction.denominator.unsigned_abs(),
+        ),
+        None => (number.value.unsigned_abs(), 1),
+    };
+    if numerator == 0 {
+        return Ok(());
+    }
+    if numerator / denominator > MAX
+        || (numerator / denominator == MAX && numerator % denominator != 0)
+    {
+        return Err(NumTokenParseError::OutOfRange);
+    }
+
+    let divisor = gcd(numerator, denominator);
+    let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    if !denominator.is_power_of_two() {
+        return Err(NumTokenParseError::InexactFloat);
+    }
+
+    let zeros = numerator.trailing_zeros();
+    let significand = numerator >> zeros;
+    let exponent = i64::from(zeros) - i64::from(denominator.trailing_zeros());
+    if significand >> SIGNIFICANT_BITS != 0 || exponent < MIN_EXPONENT {
+        return Err(NumTokenParseError::InexactFloat);
+    }
+    Ok(())
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
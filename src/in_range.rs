@@ -0,0 +1,26 @@
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree};
+
+use crate::{bounds, grid, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let parts = grid::split_punct(token_stream.into_iter().collect(), ',');
+    let [type_tokens, number_tokens] = &parts[..] else {
+        return Err(NumTokenParseError::InvalidInRange(Span::call_site()));
+    };
+    let int_type = bounds::parse_type(type_tokens)?;
+    let span = number_tokens
+        .first()
+        .map_or_else(Span::call_site, TokenTree::span);
+
+    // The type is already given in front of the number
+    let (options, tokens) = parse_options(number_tokens.iter().cloned().collect())?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+    let (_, value) = grid::evaluate_cell(tokens, options)?;
+    if !int_type.contains(value) {
+        return Err(NumTokenParseError::NotInRange(span, value, int_type));
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())).into())
+}
@@ -0,0 +1,45 @@
+use proc_macro::Literal;
+use word_nums_core::IntType;
+
+/// Creates a literal of the given type, if the value fits into it
+pub fn int_literal(int_type: IntType, value: i128) -> Option<Literal> {
+    match int_type {
+        IntType::I8 => value.try_into().ok().map(Literal::i8_suffixed),
+        IntType::I16 => value.try_into().ok().map(Literal::i16_suffixed),
+        IntType::I32 => value.try_into().ok().map(Literal::i32_suffixed),
+        IntType::I64 => value.try_into().ok().map(Literal::i64_suffixed),
+        IntType::I128 => Some(Literal::i128_suffixed(value)),
+        IntType::Isize => value.try_into().ok().map(Literal::isize_suffixed),
+        IntType::U8 => value.try_into().ok().map(Literal::u8_suffixed),
+        IntType::U16 => value.try_into().ok().map(Literal::u16_suffixed),
+        IntType::U32 => value.try_into().ok().map(Literal::u32_suffixed),
+        IntType::U64 => value.try_into().ok().map(Literal::u64_suffixed),
+        IntType::U128 => value.try_into().ok().map(Literal::u128_suffixed),
+        IntType::Usize => value.try_into().ok().map(Literal::usize_suffixed),
+    }
+}
+
+/// The radix that an integer literal is written in
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Radix {
+    #[default]
+    Decimal,
+    Hexadecimal,
+    Octal,
+    Binary,
+}
+
+/// Creates a literal of the given type written in the given radix, like
+/// `0xffu8`, if the value fits into the type
+pub fn radix_literal(int_type: IntType, value: i128, radix: Radix) -> Option<Literal> {
+    let literal = int_literal(int_type, value)?;
+    let magnitude = value.unsigned_abs();
+    let digits = match radix {
+        Radix::Decimal => return Some(literal),
+        Radix::Hexadecimal => format!("0x{magnitude:x}"),
+        Radix::Octal => format!("0o{magnitude:o}"),
+        Radix::Binary => format!("0b{magnitude:b}"),
+    };
+    let sign = if value < 0 { "-" } else { "" };
+    format!("{sign}{digits}{}", int_type.name()).parse().ok()
+}
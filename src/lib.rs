@@ -1,253 +1,2064 @@
-use proc_macro::{Ident, Literal, Span, TokenStream, TokenTree};
+#![cfg_attr(feature = "diagnostics", feature(proc_macro_diagnostic))]
+
+use std::borrow::Cow;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Spacing, Span, TokenStream, TokenTree};
+use word_nums_core::{Dialect, IntType, Language, ParseError, Scale, Sign, Token};
+
+use crate::int_type::Radix;
+
+mod bits;
+mod bounds;
+mod cents;
+mod chars;
+#[cfg(feature = "num-complex")]
+mod complex;
+mod consts;
+mod date;
+#[cfg(feature = "bigdecimal")]
+mod decimal;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod dotted;
+mod duration;
+mod enums;
+mod eq;
+mod grid;
+#[cfg(feature = "f16")]
+mod half;
+mod in_range;
+mod int_type;
+mod nonzero;
+mod overflow;
+mod percent;
+mod permissions;
+mod pow;
+mod range;
+mod ratio;
+mod set;
+mod string;
+mod suffix;
+mod table;
+mod time;
+mod wrap;
 
 type NumType = i128;
 const NUM_TOO_BIG_ERROR_MSG: &str = "You number literal is too big to fit the internal representation of the word_nums crate or any potentially generated number literal.";
 
-/// Specifies an integer literal using English words.
+/// Specifies an integer literal using English words.
+///
+/// The macro expands to the smallest possible integer type the number
+/// fits into. For example:
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(eight), 8_i8);
+/// assert_eq!(num!(four hundred), 400_i16);
+/// assert_eq!(num!(one hundred thousand), 100_000i32);
+/// ```
+///
+/// Negative numbers are denoted with a leading "negative", "minus" or `-`.
+/// Dashes anywhere else are ignored.
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(minus six), -6);
+/// assert_eq!(num!(- six), -6);
+/// assert_eq!(num!(twenty - six), 26);
+/// ```
+///
+/// By default, all integer literals are unsigned. To create a signed literal,
+/// explicitly start the number string with the words "positive" or "plus"
+/// the contents of the file.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(positive sixty four), 64_u8);
+/// assert_eq!(num!(plus two hundred seventy nine), 279_u16);
+/// ```
+///
+/// Instead of words, the macro also accepts a plain integer literal, which
+/// is given the same type as the spelled-out number would have.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(300), num!(three hundred));
+/// assert_eq!(num!(plus 0xff), 255_u8);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(forty 2);
+/// ```
+///
+/// # Options
+///
+/// The number string may be prefixed by one or more options, each followed
+/// by a colon:
+///
+///   * `loose:` ignores a single trailing word that is not a number, such as a
+///     unit. Only the very last token is stripped, unknown words anywhere else
+///     are still an error. A leading qualifier like "approximately",
+///     "exactly", "roughly" or "about" is ignored as well, and so is a
+///     leading "the". Fullwidth digits and keycap emoji pasted into a string
+///     literal are read like plain digits, and integer literals can be mixed
+///     with the words, like in "2 hundred".
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(loose: five dollars), 5);
+/// assert_eq!(num!(loose: approximately minus forty two), -42);
+/// assert_eq!(num!(loose: "４２"), 42);
+/// assert_eq!(num!(loose: 2 hundred), 200);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(five dollars);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(approximately forty two);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(loose: five dollars and ten cents);
+/// ```
+///
+///   * `approx:` allows the vague quantities "couple" (2), "few" (3) and
+///     "several" (7). They multiply like "hundred" does, so "a few thousand"
+///     is three thousand and "a couple dozen" is twenty four. The connector
+///     "of" is ignored and a leading "half of" halves the rest of the number.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(approx: a couple of hundred), 200);
+/// assert_eq!(num!(approx: a couple dozen), 24);
+/// assert_eq!(num!(approx: half of a thousand), 500);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(approx: a few of them);
+/// ```
+///
+///   * `digits:` reads the words as a sequence of digits, the way years and
+///     codes are spoken. Every word contributes all of its digits, so "twelve"
+///     is two digits and "oh" can be used for a single zero. Leading zeros are
+///     allowed. The only exception is a tens word followed by a single digit,
+///     which together make up two digits, like "twenty one". Multipliers like
+///     "hundred" can not be used.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(digits: nineteen oh five), 1905);
+/// assert_eq!(num!(digits: twenty twenty one), 2021);
+/// assert_eq!(num!(digits: zero zero seven), 7);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(digits: nineteen hundred);
+/// ```
+///
+///   * `folksy:` allows repeating a multiplier, which multiplies it with
+///     itself. Without it, repeating a multiplier or putting two count nouns in
+///     a row is an error. The count nouns "dozen", "score", "gross" and "ream"
+///     multiply with each other in this mode, so "dozen score" is 240.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(folksy: a thousand thousand), 1_000_000);
+/// assert_eq!(num!(folksy: five hundred hundred), 50_000);
+/// assert_eq!(num!(folksy: a dozen dozen), 144);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(a thousand thousand);
+/// ```
+///
+///   * `arith:` allows arithmetic on whole numbers. They can be multiplied
+///     with "times", "x" or "multiplied by", and added or subtracted with
+///     "plus" and "minus". Multiplications are evaluated first, otherwise
+///     the expression is evaluated from left to right. Parentheses group
+///     parts of the expression, and a "minus" in front of them negates the
+///     whole group.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(arith: three times four), 12);
+/// assert_eq!(num!(arith: minus six multiplied by seven), -42);
+/// assert_eq!(num!(arith: two plus three times four), 14);
+/// assert_eq!(num!(arith: (two plus three) times four), 20);
+/// assert_eq!(num!(arith: ten minus four minus three), 3);
+/// ```
+///
+///   * `ordinal:` allows the last word to be an ordinal like "second" or
+///     "hundredth", which means the same as its cardinal. An ordinal may be
+///     preceded by "the", which is otherwise only ignored in loose mode. The
+///     German and French ordinals like "dritte" and "deuxième" are read as
+///     well.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(ordinal: the forty second), 42);
+/// assert_eq!(num!(ordinal: two hundredth), 200);
+/// assert_eq!(num!(ordinal: seven), 7);
+/// assert_eq!(num!(de: ordinal: dritte), 3);
+/// assert_eq!(num!(fr: ordinal: deuxième), 2);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(ordinal: the forty two);
+/// ```
+///
+///   * `alias:` allows the abbreviated multipliers "hundo" (100) as well as
+///     "k", "thou" and "mil" (1000). Since "mil" can also mean a million, it
+///     is only read as a thousand in this mode. The unambiguous "grand" is
+///     always understood.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(five grand), 5000);
+/// assert_eq!(num!(alias: fifty k), 50_000);
+/// assert_eq!(num!(alias: three hundo), 300);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(fifty k);
+/// ```
+///
+///   * `prog:` allows "null" and "nil" for zero, the way programmers say it.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(prog: null), 0);
+/// assert_eq!(num!(prog: nil), 0);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(null);
+/// ```
+///
+///   * `strict:` rejects English numbers where two number words directly
+///     follow each other without forming tens and a unit. By default, such
+///     words are summed up, so "forty two three" is forty five.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(strict: forty two), 42);
+/// assert_eq!(num!(forty two three), 45);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(strict: forty two three);
+/// ```
+///
+///   * `roman:` reads a single Roman numeral instead of number words. Only
+///     the canonical subtractive form is accepted, so "IIII" is an error.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(roman: MCMLXXXIV), 1984);
+/// assert_eq!(num!(roman: u16: iv), 4_u16);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(roman: IIII);
+/// ```
+///
+///   * `hex:` reads a sequence of hexadecimal digits. The letters "a" to "f"
+///     and words made up of them are read as digits, just like the digit
+///     words and literals, so "f f" and "ff" are both 255.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(hex: f f), 255);
+/// assert_eq!(num!(hex: a 0), 160);
+/// assert_eq!(num!(hex: u32: dead beef), 0xdead_beef_u32);
+/// assert_eq!(num!(hex: one fifteen), 0x1f);
+/// ```
+///
+/// An integer type can be given as an option to override the smallest
+/// possible type. The sign words are still accepted, but no longer influence
+/// the type. Since negative zero is equal to zero, it is allowed for unsigned
+/// types as well.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(u32: seven), 7_u32);
+/// assert_eq!(num!(i64: plus seven), 7_i64);
+/// assert_eq!(num!(u8: minus zero), 0_u8);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(u8: minus one);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(i8: two hundred);
+/// ```
+///
+/// # Fractions
+///
+/// With the `float` feature enabled, a number can end in a fractional
+/// remainder of halves or quarters. The "and" before the fraction is
+/// required, and the macro expands to an `f64` literal instead. Anywhere
+/// else, "and" is still ignored, so "one hundred and five" is an integer.
+/// After "dozen", the fraction is a fraction of a dozen, and the number stays
+/// an integer if that is a whole number, like "a dozen and a half". Such
+/// numbers can be read without the `float` feature as well. A number can also
+/// end in a decimal point followed by single digits, like "three point one
+/// four". The sign always applies to the whole number, fraction included.
+/// The feature also allows decimal float literals in place of the words.
+///
+/// ```
+/// # use word_nums::num;
+/// # #[cfg(feature = "float")]
+/// # {
+/// assert_eq!(num!(three and a half), 3.5_f64);
+/// assert_eq!(num!(minus two and three quarters), -2.75_f64);
+/// assert_eq!(num!(minus three point five), -3.5_f64);
+/// assert_eq!(num!(one hundred and five), 105_i8);
+/// assert_eq!(num!(a dozen and a half), 18_i8);
+/// assert_eq!(num!(minus 1.25), -1.25_f64);
+/// # }
+/// ```
+///
+/// The `fractions:` option also allows fractions that are named by their
+/// denominator, which may be "tenths", "hundredths", "thousandths" or
+/// "millionths". The whole part is separated from them with "whole".
+///
+/// ```
+/// # use word_nums::num;
+/// # #[cfg(feature = "float")]
+/// # {
+/// assert_eq!(num!(fractions: three whole five tenths), 3.5_f64);
+/// assert_eq!(num!(fractions: two whole twenty five hundredths), 2.25_f64);
+/// assert_eq!(num!(fractions: seventy five hundredths), 0.75_f64);
+/// # }
+/// ```
+///
+/// # Languages
+///
+/// Numbers are read as English by default. Other languages can be selected
+/// with an option:
+///
+///   * `pl:` reads Polish cardinals. All grammatical forms of the large
+///     multipliers are accepted, so "tysiąc", "tysiące" and "tysięcy" all
+///     mean one thousand.
+///   * `ko:` reads romanized Sino-Korean numerals. Digits are grouped in fours
+///     like in the original script, so "sam man" is thirty thousand.
+///   * `tr:` reads Turkish cardinals. A hundred and a thousand are written
+///     without a count, so "bin yüz" is 1100.
+///   * `el:` reads transliterated Greek cardinals in all of their gendered
+///     forms. The connector "kai" is ignored just like "and".
+///   * `he:` reads transliterated Hebrew cardinals in both genders. Rust
+///     does not allow apostrophes inside of words, so the connector "ve" is
+///     written on its own or with a dash, as in "esrim ve-echad".
+///   * `de:` reads German cardinals, which may be written as one compound
+///     word like "dreiundzwanzig". German uses the long scale, so a "Billion"
+///     is a million millions.
+///   * `fr:` reads French cardinals like "quatre-vingt-dix", including the
+///     Belgian and Swiss "septante", "huitante" and "nonante".
+///   * `sv:` reads Swedish cardinals, which may be written as one compound
+///     word like "tjugoett" just like in German.
+///   * `vi:` reads Vietnamese cardinals like "hai mươi mốt", including the
+///     forms "mốt", "tư" and "lăm" of the digits after the tens. The words
+///     may also be written without their diacritics.
+///   * `fi:` reads Finnish cardinals, which are written as one compound word
+///     like "kaksikymmentäyksi". The multipliers take their partitive form
+///     after a count, as in "kolmesataa".
+///
+/// The English sign words are understood in every language, in addition to
+/// the language's own words like the French "moins". The connector on the
+/// other hand is only ignored in the selected language, so "und" is accepted
+/// in German but "and" is not.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(pl: dwadzieścia jeden), 21);
+/// assert_eq!(num!(pl: pięć tysięcy), 5000);
+/// assert_eq!(num!(ko: i cheon sam baek man), 23_000_000);
+/// assert_eq!(num!(tr: yüz yirmi üç), 123);
+/// assert_eq!(num!(el: dyo chiliades kai ena), 2001);
+/// assert_eq!(num!(he: esrim ve-echad), 21);
+/// assert_eq!(num!(de: zweihundertdreiundvierzig), 243);
+/// assert_eq!(num!(fr: moins vingt-et-un), -21);
+/// assert_eq!(num!(sv: tvåhundra fyrtiotre), 243);
+/// assert_eq!(num!(vi: hai trăm bốn mươi ba), 243);
+/// assert_eq!(num!(fi: kaksisataaneljäkymmentäkolme), 243);
+/// ```
+///
+/// English numbers can also be read as one of its regional variants:
+///
+///   * `us:` reads American English, which is the default.
+///   * `gb:` reads British English, which requires an "and" before the last
+///     part below a hundred whenever there is a larger part before it.
+///   * `detect:` reads both, but requires the "and" to be used consistently.
+///     If there is one, it has to be everywhere British English would put it.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(us: one hundred one), 101);
+/// assert_eq!(num!(gb: one hundred and one), 101);
+/// assert_eq!(num!(gb: two thousand three hundred), 2300);
+/// assert_eq!(num!(detect: one hundred one), 101);
+/// assert_eq!(num!(detect: one hundred and one), 101);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(gb: one hundred one);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(detect: one hundred and one thousand two hundred one);
+/// ```
+///
+/// The multipliers above a million are read in the short scale by default,
+/// where a billion is a thousand millions:
+///
+///   * `short:` reads the short scale, which is the default.
+///   * `long:` reads the long scale, where every multiplier is a million times
+///     the previous one, so a billion is a million millions.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(short: two billion), 2_000_000_000);
+/// assert_eq!(num!(long: two billion), 2_000_000_000_000);
+/// assert_eq!(num!(long: one thousand million), 1_000_000_000);
+/// ```
+///
+/// Since the scale differs by region, the nightly-only `diagnostics` feature
+/// adds a note whenever a billion or more is read without either option.
+///
+/// # String literals
+///
+/// String literals can be mixed with the other tokens, which is useful for
+/// macros that build up their input. The words of each string are read from
+/// left to right in its place, as if they had been written out with
+/// whitespace around them.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(forty "two"), 42);
+/// assert_eq!(num!("three hundred" and "twenty-one"), 321);
+/// ```
+///
+/// # Radix
+///
+/// The literal can be written in another radix with one of the options
+/// `hex_lit:`, `oct_lit:` and `bin_lit:`, which makes generated code easier to
+/// read when the number is a mask or a flag. Only the text of the literal
+/// changes, so `0xffu8` has the same value and type as `255u8`.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(hex_lit: u8: two hundred fifty five), 0xff_u8);
+/// assert_eq!(num!(oct_lit: u32: five hundred eleven), 0o777_u32);
+/// assert_eq!(num!(bin_lit: minus five), -0b101_i8);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(hex_lit: one and a half);
+/// ```
+///
+/// # Suffixes
+///
+/// A custom suffix can be given as the very first option. The number is then
+/// written in decimal and directly followed by the suffix, which is useful for
+/// other macros that accept their own literals. Since the digits are always
+/// decimal, a suffix can not be combined with a radix option, and a fraction
+/// can not have an integer suffix like `u8`.
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(suffix = "f32": forty two), 42_f32);
+/// assert_eq!(num!(suffix = "_u16": minus zero), 0_u16);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num;
+/// let _ = num!(suffix = "f-32": forty two);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number literal is invalid or could not be parsed
+///   * The number literal is too larger than `i128::MAX`
+///   * The number literal does not fit the explicitly requested type
+///   * A fraction is used anywhere but at the end of the number
+///   * A fraction is used together with an integer type or a radix option
+///   * The suffix is not a valid identifier or used with an integer type or
+///     radix option
+///   * The suffix is an integer type, but the number has a fractional part
+///
+/// # Examples
+///
+/// ```
+/// # use word_nums::num;
+/// assert_eq!(num!(forty two), 42);
+/// assert_eq!(num!(minus one thousand three hundred thirty seven), -1337);
+/// ```
+#[proc_macro]
+pub fn num(token_stream: TokenStream) -> TokenStream {
+    let literal = suffix::take_suffix(token_stream).and_then(|(suffix, token_stream)| {
+        let number = evaluate(token_stream)?;
+        suffix.map_or_else(
+            || make_literal(number),
+            |suffix| suffix::make_suffixed_literal(number, &suffix),
+        )
+    });
+
+    match literal {
+        Ok(literal) => literal_stream(literal),
+        Err(err) => compile_error(err),
+    }
+}
+
+/// Specifies an array length or index using English words.
+///
+/// Unlike [`num!`], this macro always expands to a `usize` literal, so it can
+/// be used anywhere a length is expected:
+///
+/// ```
+/// # use word_nums::num_len;
+/// const LEN: usize = num_len!(four);
+/// let buf = [0u8; num_len!(sixteen)];
+/// assert_eq!(buf.len(), 16);
+/// assert_eq!(LEN, 4);
+/// ```
+///
+/// As a const generic argument, the macro has to be wrapped in braces. The
+/// literal of [`num!`] can also be cast there, since casts are allowed in
+/// const expressions.
+///
+/// ```
+/// # use word_nums::{num, num_len};
+/// struct Buffer<const N: usize>([u8; N]);
+///
+/// let small = Buffer::<{ num_len!(four) }>([0; 4]);
+/// let large = Buffer::<{ num!(three hundred) as usize }>([0; 300]);
+/// assert_eq!(small.0.len() + large.0.len(), 304);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number literal is invalid, empty or could not be parsed
+///   * The number literal is negative
+///   * The number literal is larger than `usize::MAX`
+///   * An integer type option is given
+///   * The number has a fractional part
+///
+/// ```compile_fail
+/// # use word_nums::num_len;
+/// let buf = [0u8; num_len!(minus four)];
+/// ```
+#[proc_macro]
+pub fn num_len(token_stream: TokenStream) -> TokenStream {
+    match evaluate(token_stream) {
+        Ok(number) if number.options.int_type.is_some() => {
+            compile_error(NumTokenParseError::UnexpectedIntType)
+        }
+        Ok(number) if number.parsed.fraction.is_some() => {
+            compile_error(NumTokenParseError::FractionalInteger)
+        }
+        Ok(number) if number.parsed.value < 0 => compile_error(NumTokenParseError::NegativeLength),
+        Ok(number) => usize::try_from(number.parsed.value).map_or_else(
+            |_| compile_error(NumTokenParseError::OutOfRange),
+            |value| literal_stream(Literal::usize_suffixed(value)),
+        ),
+        Err(err) => compile_error(err),
+    }
+}
+
+/// Computes the number of bits needed to store a number written in English
+/// words.
+///
+/// The macro always expands to a `usize` literal. Numbers that are not
+/// negative are counted as unsigned, so zero needs no bits at all. Negative
+/// numbers are counted in two's complement, including their sign bit.
+///
+/// ```
+/// # use word_nums::num_bitwidth;
+/// assert_eq!(num_bitwidth!(two hundred fifty five), 8);
+/// assert_eq!(num_bitwidth!(two hundred fifty six), 9);
+/// assert_eq!(num_bitwidth!(minus one hundred twenty eight), 8);
+/// assert_eq!(num_bitwidth!(zero), 0);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number literal is invalid, empty or could not be parsed
+///   * An integer type option is given
+///   * The number has a fractional part
+#[proc_macro]
+pub fn num_bitwidth(token_stream: TokenStream) -> TokenStream {
+    match evaluate(token_stream) {
+        Ok(number) if number.options.int_type.is_some() => {
+            compile_error(NumTokenParseError::UnexpectedIntType)
+        }
+        Ok(number) if number.parsed.fraction.is_some() => {
+            compile_error(NumTokenParseError::FractionalInteger)
+        }
+        Ok(number) => literal_stream(Literal::usize_suffixed(bit_width(number.parsed.value))),
+        Err(err) => compile_error(err),
+    }
+}
+
+/// Specifies the bit pattern of a number written in English words as an
+/// array of `bool`s.
+///
+/// The array has one element for every bit of the type, which is the same
+/// type [`num!`] would give the number, unless a type option is given. The
+/// bits are ordered from the most significant to the least significant one,
+/// unless the leading `lsb:` option reverses them. Negative numbers are
+/// written in two's complement.
+///
+/// ```
+/// # use word_nums::num_bits;
+/// assert_eq!(
+///     num_bits!(u8: five),
+///     [false, false, false, false, false, true, false, true]
+/// );
+/// assert_eq!(
+///     num_bits!(lsb: u8: five),
+///     [true, false, true, false, false, false, false, false]
+/// );
+/// assert_eq!(num_bits!(minus one), [true; 8]);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number is invalid, empty or could not be parsed
+///   * The number has a fractional part
+///   * The number does not fit into the requested type
+///   * The type is `isize` or `usize`, whose size depends on the target
+///
+/// ```compile_fail
+/// # use word_nums::num_bits;
+/// let bits = num_bits!(usize: five);
+/// ```
+#[proc_macro]
+pub fn num_bits(token_stream: TokenStream) -> TokenStream {
+    bits::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies an amount of money in cents using English words.
+///
+/// The whole amount may be followed by "and" and the cents, like in "three
+/// and fifty" for 350 cents. Only the last "and" separates the cents, so it can
+/// still be used within the whole amount. The result gets the same type as
+/// [`num!`] would give it.
+///
+/// ```
+/// # use word_nums::num_cents;
+/// assert_eq!(num_cents!(three and fifty), 350_i16);
+/// assert_eq!(num_cents!(three), 300_i16);
+/// assert_eq!(num_cents!(minus two and five), -205_i16);
+/// assert_eq!(num_cents!(u32: one hundred and one and ninety nine), 10_199_u32);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Either of the numbers is invalid, empty or could not be parsed
+///   * The cents are not a whole number from zero to ninety nine
+///   * The amount does not fit the explicitly requested type
+///
+/// ```compile_fail
+/// # use word_nums::num_cents;
+/// let _ = num_cents!(three and one hundred);
+/// ```
+#[proc_macro]
+pub fn num_cents(token_stream: TokenStream) -> TokenStream {
+    cents::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a percentage using English words.
+///
+/// A plain percentage always expands to an `f64` literal. Followed by "of"
+/// and another number, the macro instead expands to that percentage of the
+/// number, which has to be a whole number. The result gets the same type as
+/// [`num!`] would give it, based on the number after "of".
+///
+/// ```
+/// # use word_nums::num_percent;
+/// assert_eq!(num_percent!(fifty percent), 0.5);
+/// assert_eq!(num_percent!(minus five percent), -0.05);
+/// assert_eq!(num_percent!(fifty percent of two hundred), 100_i8);
+/// assert_eq!(num_percent!(u32: ten percent of ninety thousand), 9000_u32);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Either of the numbers is invalid or could not be parsed
+///   * The word "percent" is missing
+///   * The percentage of a number is not a whole number
+///   * A plain percentage is given an integer type option
+///
+/// ```compile_fail
+/// # use word_nums::num_percent;
+/// let _ = num_percent!(fifty percent of three);
+/// ```
+#[proc_macro]
+pub fn num_percent(token_stream: TokenStream) -> TokenStream {
+    percent::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a ratio as a `(numerator, denominator)` tuple in lowest terms
+/// using English words.
+///
+/// The numerator and the denominator are separated by "per", "over" or
+/// "out of". The ratio is reduced by their greatest common divisor, and its
+/// sign is always moved into the numerator. Both share the smallest integer
+/// type that they fit into, unless a type option is given. Just like with
+/// [`num_grid!`], that type is only unsigned if both numbers explicitly start
+/// with "plus" or "positive".
+///
+/// ```
+/// # use word_nums::num_ratio_reduced;
+/// assert_eq!(num_ratio_reduced!(fifty per hundred), (1_i8, 2_i8));
+/// assert_eq!(num_ratio_reduced!(zero over five), (0, 1));
+/// assert_eq!(num_ratio_reduced!(six over minus four), (-3, 2));
+/// assert_eq!(num_ratio_reduced!(three out of four), (3, 4));
+/// assert_eq!(num_ratio_reduced!(u32: three hundred over nine hundred), (1_u32, 3_u32));
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Either of the numbers is invalid, empty or could not be parsed
+///   * Either of the numbers has a fractional part
+///   * The separator is missing
+///   * The denominator is zero
+///
+/// ```compile_fail
+/// # use word_nums::num_ratio_reduced;
+/// let _ = num_ratio_reduced!(one over zero);
+/// ```
+#[proc_macro]
+pub fn num_ratio_reduced(token_stream: TokenStream) -> TokenStream {
+    ratio::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a half-open [`Range`](core::ops::Range) using English words.
+///
+/// The start and the end are separated by "to", and the end is excluded from
+/// the range. Both share the smallest integer type that they fit into, unless
+/// a type option is given. Just like with [`num_grid!`], that type is only
+/// unsigned if both numbers explicitly start with "plus" or "positive".
+///
+/// ```
+/// # use word_nums::num_range_ex;
+/// assert_eq!(num_range_ex!(one to ten), 1_i8..10_i8);
+/// assert_eq!(num_range_ex!(minus five to five hundred), -5_i16..500_i16);
+/// assert_eq!(num_range_ex!(u32: zero to sixty four), 0_u32..64_u32);
+/// assert!(num_range_ex!(three to three).is_empty());
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Either of the numbers is invalid, empty or could not be parsed
+///   * Either of the numbers has a fractional part
+///   * The "to" between the numbers is missing
+///   * The start is larger than the end
+///
+/// ```compile_fail
+/// # use word_nums::num_range_ex;
+/// let _ = num_range_ex!(ten to one);
+/// ```
+#[proc_macro]
+pub fn num_range_ex(token_stream: TokenStream) -> TokenStream {
+    range::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Asserts at compile time that two numbers written in words are equal.
+///
+/// The numbers are separated by a semicolon and each of them takes its own
+/// options, so numbers in different languages can be compared. The macro
+/// expands to `()` if the numbers are equal, and to a compile error otherwise.
+///
+/// ```
+/// # use word_nums::num_eq;
+/// num_eq!(one thousand; thousand);
+/// num_eq!(de: einundzwanzig; twenty one);
+/// num_eq!(alias: ten k; ten thousand);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Either of the numbers is invalid, empty or could not be parsed
+///   * There are not exactly two numbers
+///   * The numbers are not equal
+///
+/// ```compile_fail
+/// # use word_nums::num_eq;
+/// num_eq!(one thousand; hundred);
+/// ```
+#[proc_macro]
+pub fn num_eq(token_stream: TokenStream) -> TokenStream {
+    eq::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a power like "two to the eighth" using English words.
+///
+/// The base is followed by "to" and the exponent, which is written as an
+/// ordinal with an optional "the" in front of it. The result is computed at
+/// compile time and has the smallest type that it fits into, just like with
+/// [`num!`]. All options of [`num!`] are accepted and apply to the result.
+///
+/// ```
+/// # use word_nums::num_pow;
+/// assert_eq!(num_pow!(two to the eighth), 256_i16);
+/// assert_eq!(num_pow!(ten to the third), 1000_i16);
+/// assert_eq!(num_pow!(minus three to the third), -27_i8);
+/// assert_eq!(num_pow!(u64: two to the fortieth), 1_u64 << 40);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The base or the exponent is invalid, empty or could not be parsed
+///   * There is no "to" before the exponent
+///   * The exponent is negative or larger than a `u32`
+///   * The result overflows the internal representation or the requested type
+///
+/// ```compile_fail
+/// # use word_nums::num_pow;
+/// let _ = num_pow!(ten to the fortieth);
+/// ```
+#[proc_macro]
+pub fn num_pow(token_stream: TokenStream) -> TokenStream {
+    pow::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies an arbitrary-precision `BigDecimal` using English words.
+///
+/// This macro is only available with the `bigdecimal` feature, which also
+/// enables `float`. The number is read just like with [`num!`], but it
+/// expands to a `bigdecimal::BigDecimal` constructed from its exact decimal
+/// digits, so none of the precision is lost to an `f64`. The calling crate
+/// has to depend on `bigdecimal` itself.
+///
+/// ```
+/// # #[cfg(feature = "bigdecimal")]
+/// # {
+/// # use word_nums::num_bigdecimal;
+/// use bigdecimal::BigDecimal;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     num_bigdecimal!(three point one four one five nine),
+///     BigDecimal::from_str("3.14159").unwrap()
+/// );
+/// assert_eq!(num_bigdecimal!(minus forty two), BigDecimal::from(-42));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number is invalid, empty or could not be parsed
+///   * An integer type option is given
+#[cfg(feature = "bigdecimal")]
+#[proc_macro]
+pub fn num_bigdecimal(token_stream: TokenStream) -> TokenStream {
+    decimal::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a half-precision `f16` using English words.
+///
+/// This macro is only available with the `f16` feature, which also enables
+/// `float`. The number is read just like with [`num!`], but it expands to an
+/// `f16` literal. Since an `f16` only has 11 significant bits, the number has
+/// to be exactly representable, so "one point one" is rejected instead of
+/// being rounded. The calling crate needs a nightly compiler with
+/// `#![feature(f16)]`.
+///
+/// ```
+/// # #![feature(f16)]
+/// # use word_nums::num_f16;
+/// assert_eq!(num_f16!(forty two), 42.0_f16);
+/// assert_eq!(num_f16!(minus two and three quarters), -2.75_f16);
+/// assert_eq!(num_f16!(sixty five thousand five hundred four), f16::MAX);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number is invalid, empty or could not be parsed
+///   * An integer type option is given
+///   * The number is larger than the largest `f16`
+///   * The number can not be represented exactly as an `f16`
+///
+/// ```compile_fail
+/// # #![feature(f16)]
+/// # use word_nums::num_f16;
+/// let _ = num_f16!(one point one);
+/// ```
+#[cfg(feature = "f16")]
+#[proc_macro]
+pub fn num_f16(token_stream: TokenStream) -> TokenStream {
+    half::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a Gaussian integer as a `num_complex::Complex` using English
+/// words.
+///
+/// This macro is only available with the `num-complex` feature. A trailing
+/// "i" marks the imaginary part, which is separated from the real part by
+/// "plus" or "minus". Either part can be left out, and a lone "i" stands for
+/// one "i". The components are `i32` unless an integer type option is given.
+/// The calling crate has to depend on `num-complex` itself.
+///
+/// ```
+/// # #[cfg(feature = "num-complex")]
+/// # {
+/// # use word_nums::num_complex;
+/// use num_complex::Complex;
+///
+/// assert_eq!(num_complex!(three plus four i), Complex::new(3, 4));
+/// assert_eq!(num_complex!(minus four i), Complex::new(0, -4));
+/// assert_eq!(num_complex!(i64: five minus i), Complex::new(5_i64, -1));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Either of the parts is invalid or could not be parsed
+///   * Either of the parts has a fractional part
+///   * Either of the parts does not fit into the component type
+#[cfg(feature = "num-complex")]
+#[proc_macro]
+pub fn num_complex(token_stream: TokenStream) -> TokenStream {
+    complex::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Converts a number written in English words into a string literal of its
+/// digits.
+///
+/// The number is written in decimal, unless one of the `hex_lit:`, `oct_lit:`
+/// or `bin_lit:` options of [`num!`] selects another radix. Negative numbers
+/// keep their minus sign in any radix. All other options of [`num!`] are
+/// accepted as well, so `hex:` reads hexadecimal digit words. Every digit
+/// after a decimal point is kept, including trailing zeros.
+///
+/// ```
+/// # use word_nums::num_str;
+/// assert_eq!(num_str!(forty two), "42");
+/// assert_eq!(num_str!(minus ten), "-10");
+/// # #[cfg(feature = "float")]
+/// assert_eq!(num_str!(three point one zero), "3.10");
+/// assert_eq!(num_str!(hex_lit: two hundred fifty five), "ff");
+/// assert_eq!(num_str!(bin_lit: minus five), "-101");
+/// assert_eq!(num_str!(hex: f f), "255");
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number literal is invalid or could not be parsed
+///   * The number does not fit the explicitly requested type
+///   * A fractional number is written in another radix than decimal
+#[proc_macro]
+pub fn num_str(token_stream: TokenStream) -> TokenStream {
+    string::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Converts a number written in English words into a C string literal of its
+/// digits, for passing the number to C functions.
+///
+/// This accepts exactly the same input as [`num_str!`], but the result is a
+/// `&CStr` that ends in a NUL byte.
+///
+/// ```
+/// # use word_nums::num_cstr;
+/// assert_eq!(num_cstr!(forty two).to_str(), Ok("42"));
+/// assert_eq!(num_cstr!(hex_lit: two hundred fifty five), c"ff");
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number literal is invalid or could not be parsed
+///   * The number does not fit the explicitly requested type
+///   * A fractional number is written in another radix than decimal
+#[proc_macro]
+pub fn num_cstr(token_stream: TokenStream) -> TokenStream {
+    string::expand_cstr(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Converts a sequence of English digit words into an array of the digit
+/// characters.
+///
+/// Every word has to be a single digit from zero to nine. All options of
+/// [`num!`] except for the integer types are accepted, so `digits:` allows
+/// "oh" for zero.
+///
+/// ```
+/// # use word_nums::num_char_array;
+/// assert_eq!(num_char_array!(one two three), ['1', '2', '3']);
+/// assert_eq!(num_char_array!(digits: four oh four), ['4', '0', '4']);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Any of the words is not a single digit, like "ten" or "hundred"
+///   * There are no digits at all
+///
+/// ```compile_fail
+/// # use word_nums::num_char_array;
+/// let _ = num_char_array!(one ten);
+/// ```
+#[proc_macro]
+pub fn num_char_array(token_stream: TokenStream) -> TokenStream {
+    chars::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a Unix file mode like `0o755u32` using English digit words.
+///
+/// Every word is a single octal digit from zero to seven, and there have to
+/// be three digits for the permissions, or four to include the special bits
+/// like setuid in front of them.
+///
+/// ```
+/// # use word_nums::num_octal_permissions;
+/// assert_eq!(num_octal_permissions!(seven five five), 0o755_u32);
+/// assert_eq!(num_octal_permissions!(four seven five five), 0o4755_u32);
+/// assert_eq!(num_octal_permissions!(digits: oh six four four), 0o644_u32);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Any of the words is not a single octal digit, like "eight" or "ten"
+///   * There are not three or four digits
+///
+/// ```compile_fail
+/// # use word_nums::num_octal_permissions;
+/// let _ = num_octal_permissions!(seven eight five);
+/// ```
+#[proc_macro]
+pub fn num_octal_permissions(token_stream: TokenStream) -> TokenStream {
+    permissions::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies an array of the components of a dotted number like a version,
+/// using English words.
+///
+/// The components are separated by the word "dot" or by a period and there
+/// can be any number of them. They share the smallest unsigned integer type
+/// that every component fits into, unless a type option is given. Components
+/// are never negative, even if the type option is a signed type.
+///
+/// ```
+/// # use word_nums::num_dotted;
+/// assert_eq!(num_dotted!(one dot two dot three dot four), [1_u8, 2, 3, 4]);
+/// assert_eq!(num_dotted!(two . three hundred), [2_u16, 300]);
+/// assert_eq!(num_dotted!(u32: seven), [7_u32]);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Any of the components is invalid, empty or could not be parsed
+///   * Any of the components is negative or has a fractional part
+///
+/// ```compile_fail
+/// # use word_nums::num_dotted;
+/// let _ = num_dotted!(one dot minus two);
+/// ```
+#[proc_macro]
+pub fn num_dotted(token_stream: TokenStream) -> TokenStream {
+    dotted::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a date as a `(year, month, day)` tuple using English words.
+///
+/// The year, the month and the day are separated by semicolons. The year is a
+/// `u16` and the month and the day are `u8`s.
+///
+/// ```
+/// # use word_nums::num_date;
+/// assert_eq!(
+///     num_date!(two thousand twenty four; three; fifteen),
+///     (2024_u16, 3_u8, 15_u8)
+/// );
+/// assert_eq!(num_date!(nineteen hundred ninety nine; twelve; thirty one), (1999, 12, 31));
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * There are not exactly three components
+///   * Any of the components is invalid, empty or could not be parsed
+///   * The month is not between one and twelve or the day is not between one
+///     and thirty one
+///
+/// The day is not checked against the length of the month, so the 31st of
+/// February is accepted.
+///
+/// ```compile_fail
+/// # use word_nums::num_date;
+/// let _ = num_date!(two thousand; thirteen; one);
+/// ```
+#[proc_macro]
+pub fn num_date(token_stream: TokenStream) -> TokenStream {
+    date::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Asserts at compile time that a number written in words fits into an
+/// integer type.
 ///
-/// The macro expands to the smallest possible integer type the number
-/// fits into. For example:
+/// The type is given by its name, followed by a comma and the number along
+/// with its options. The macro expands to `()` if the number fits, and to a
+/// compile error otherwise, so it can be used in constant assertions.
 ///
 /// ```
-/// # use word_nums::num;
-/// assert_eq!(num!(eight), 8_i8);
-/// assert_eq!(num!(four hundred), 400_i16);
-/// assert_eq!(num!(one hundred thousand), 100_000i32);
+/// # use word_nums::num_in_range;
+/// num_in_range!(u8, forty two);
+/// num_in_range!(i8, minus one hundred twenty eight);
+/// const _: () = num_in_range!(u16, de: sechzigtausend);
 /// ```
 ///
-/// Negative numbers are denoted with a leading "negative" or "minus".
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The type is not the name of a primitive integer type
+///   * The number is invalid, empty or could not be parsed
+///   * The number has a fractional part
+///   * The number is given an integer type option
+///   * The number does not fit into the type
+///
+/// ```compile_fail
+/// # use word_nums::num_in_range;
+/// num_in_range!(u8, two hundred fifty six);
 /// ```
-/// # use word_nums::num;
-/// assert_eq!(num!(minus six), -6);
+#[proc_macro]
+pub fn num_in_range(token_stream: TokenStream) -> TokenStream {
+    in_range::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a time of day as an `(hour, minute)` tuple of `u8`s using English
+/// words.
+///
+/// The hour and the minutes are separated by a semicolon, and the minutes may
+/// be left out for a full hour. The hour can also be "noon" or "midnight", and
+/// a single hour may be preceded by "half past", "quarter past" or
+/// "quarter to".
+///
+/// ```
+/// # use word_nums::num_time;
+/// assert_eq!(num_time!(fourteen; thirty), (14_u8, 30_u8));
+/// assert_eq!(num_time!(seven), (7, 0));
+/// assert_eq!(num_time!(noon), (12, 0));
+/// assert_eq!(num_time!(midnight), (0, 0));
+/// assert_eq!(num_time!(half past noon), (12, 30));
+/// assert_eq!(num_time!(quarter to midnight), (23, 45));
 /// ```
 ///
-/// By default, all integer literals are unsigned. To create a signed literal,
-/// explicitly start the number string with the words "positive" or "plus"
-/// the contents of the file.
+/// # Panics
 ///
+/// This macro will panic at compile time if:
+///   * There are more than two components
+///   * Any of the components is invalid, empty or could not be parsed
+///   * The hour is not between zero and twenty three or the minutes are not
+///     between zero and fifty nine
+///
+/// ```compile_fail
+/// # use word_nums::num_time;
+/// let _ = num_time!(twenty four; zero);
 /// ```
-/// # use word_nums::num;
-/// assert_eq!(num!(positive sixty four), 64_u8);
-/// assert_eq!(num!(plus two hundred seventy nine), 279_u16);
+#[proc_macro]
+pub fn num_time(token_stream: TokenStream) -> TokenStream {
+    time::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Expands to the largest value of an integer type as a literal of that type.
+///
+/// The type is given by its name, just like the type options of [`num!`].
+///
+/// ```
+/// # use word_nums::num_max_for;
+/// assert_eq!(num_max_for!(u8), 255_u8);
+/// assert_eq!(num_max_for!(i16), 32_767_i16);
+/// assert_eq!(num_max_for!(usize), usize::MAX);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if the input is not the name of a
+/// primitive integer type.
+///
+/// ```compile_fail
+/// # use word_nums::num_max_for;
+/// let _ = num_max_for!(f32);
+/// ```
+#[proc_macro]
+pub fn num_max_for(token_stream: TokenStream) -> TokenStream {
+    bounds::expand(token_stream, bounds::Bound::Max).unwrap_or_else(compile_error)
+}
+
+/// Expands to the smallest value of an integer type as a literal of that type.
+///
+/// This is the counterpart of [`num_max_for!`].
+///
+/// ```
+/// # use word_nums::num_min_for;
+/// assert_eq!(num_min_for!(i8), -128_i8);
+/// assert_eq!(num_min_for!(u64), 0_u64);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if the input is not the name of a
+/// primitive integer type.
+#[proc_macro]
+pub fn num_min_for(token_stream: TokenStream) -> TokenStream {
+    bounds::expand(token_stream, bounds::Bound::Min).unwrap_or_else(compile_error)
+}
+
+/// Specifies a two-dimensional array of integers using English words.
+///
+/// Rows are separated by semicolons and the numbers within a row by commas.
+/// All elements share the smallest integer type that every number fits into,
+/// unless a type option is given. Just like with [`num!`], that type is only
+/// unsigned if every number explicitly starts with "plus" or "positive".
+///
+/// ```
+/// # use word_nums::num_grid;
+/// let grid = num_grid!(u8: one, two; three, four);
+/// assert_eq!(grid, [[1_u8, 2], [3, 4]]);
+/// assert_eq!(num_grid!(one; two hundred), [[1_i16], [200]]);
 /// ```
 ///
 /// # Panics
 ///
 /// This macro will panic at compile time if:
-///   * The number literal is invalid or could not be parsed
-///   * The number literal is too larger than `i128::MAX`
+///   * Any of the numbers is invalid or could not be parsed
+///   * The rows have different lengths
+///   * Any of the numbers is empty or has a fractional part
 ///
-/// # Examples
+/// ```compile_fail
+/// # use word_nums::num_grid;
+/// let grid = num_grid!(one, two; three);
+/// ```
+#[proc_macro]
+pub fn num_grid(token_stream: TokenStream) -> TokenStream {
+    grid::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a [`Duration`](core::time::Duration) using English words.
+///
+/// The duration is made up of one or more numbers, each followed by its unit.
+/// The units range from nanoseconds to weeks and can be given in any order,
+/// but every unit only once. All options of [`num!`] except for the integer
+/// types are accepted. The macro expands to a call of the `const` function
+/// `Duration::new`, so it can be used to initialize constants.
 ///
 /// ```
-/// # use word_nums::num;
-/// assert_eq!(num!(forty two), 42);
-/// assert_eq!(num!(minus one thousand three hundred thirty seven), -1337);
+/// # use word_nums::num_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(num_duration!(two hours thirty minutes), Duration::from_secs(9000));
+/// assert_eq!(num_duration!(one minute and thirty seconds), Duration::from_secs(90));
+/// assert_eq!(num_duration!(five hundred milliseconds), Duration::from_millis(500));
+///
+/// const TIMEOUT: Duration = num_duration!(five seconds);
+/// assert_eq!(TIMEOUT, Duration::from_secs(5));
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Any of the numbers is invalid, could not be parsed or has a fractional part
+///   * A number is not followed by a unit
+///   * A unit is given more than once
+///   * The total duration is negative or does not fit into a `Duration`
+///
+/// ```compile_fail
+/// # use word_nums::num_duration;
+/// let _ = num_duration!(one minute two minutes);
+/// ```
+///
+/// ```compile_fail
+/// # use word_nums::num_duration;
+/// let _ = num_duration!(minus five seconds);
 /// ```
 #[proc_macro]
-pub fn num(token_stream: TokenStream) -> TokenStream {
-    match parse_tokens(token_stream) {
-        Ok(mut num_tokens) => {
-            let sign = get_sign(&mut num_tokens);
-
-            // Add the implicit 1 at the start for number strings that start with
-            // a multiplier, like "hundred fifity two"
-            if let Some(NumToken::Multiplier(_)) = num_tokens.first() {
-                num_tokens.insert(0, NumToken::Literal(1));
-            }
+pub fn num_duration(token_stream: TokenStream) -> TokenStream {
+    duration::expand(token_stream).unwrap_or_else(compile_error)
+}
 
-            // Generate the number literal
-            let mut sum: NumType = 0;
-            let mut acc: NumType = 0;
-            for (i, num_token) in num_tokens.iter().enumerate() {
-                match num_token {
-                    NumToken::Literal(value) => {
-                        acc = acc.checked_add(*value).expect(NUM_TOO_BIG_ERROR_MSG);
-                    }
-                    NumToken::Multiplier(value) => {
-                        acc *= value;
-                        if !num_tokens
-                            .iter()
-                            .skip(i + 1)
-                            .any(|x| is_larger_multiplier(*x, *value))
-                        {
-                            sum = sum.checked_add(acc).expect(NUM_TOO_BIG_ERROR_MSG);
-                            acc = 0;
-                        }
-                    }
+/// Specifies a [`NonZero`](core::num::NonZero) number using English words.
+///
+/// The number takes the same options as [`num!`] and is checked to be nonzero
+/// at compile time. The macro expands to `NonZero::new(..).unwrap()`, which
+/// can also be used to initialize constants.
+///
+/// ```
+/// # use word_nums::num_nonzero;
+/// use std::num::NonZero;
+///
+/// assert_eq!(num_nonzero!(forty two).get(), 42_i8);
+/// assert_eq!(num_nonzero!(u32: one thousand).get(), 1000_u32);
+///
+/// const LIMIT: NonZero<u16> = num_nonzero!(u16: five hundred);
+/// assert_eq!(LIMIT.get(), 500);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The number could not be passed to [`num!`]
+///   * The number has a fractional part
+///   * The number is zero
+///
+/// ```compile_fail
+/// # use word_nums::num_nonzero;
+/// let _ = num_nonzero!(minus zero);
+/// ```
+#[proc_macro]
+pub fn num_nonzero(token_stream: TokenStream) -> TokenStream {
+    nonzero::expand(token_stream, nonzero::Construction::Checked).unwrap_or_else(compile_error)
+}
 
-                    // Any subsequent signs are invalid and should be ignored.
-                    // We should never get here anyways, because parse_tokens is going to return an error in this case.
-                    NumToken::Sign(_) => {}
-                }
-            }
+/// Specifies a [`NonZero`](core::num::NonZero) number using English words,
+/// without checking it at runtime.
+///
+/// This works just like [`num_nonzero!`], but expands to
+/// `NonZero::new_unchecked(..)` in an `unsafe` block instead. The call is safe,
+/// because the number is already checked to be nonzero at compile time. This
+/// skips the check in unoptimized builds, but the macro can not be used in
+/// crates that forbid `unsafe` code.
+///
+/// ```
+/// # use word_nums::num_nonzero_unchecked;
+/// assert_eq!(num_nonzero_unchecked!(u64: seven).get(), 7_u64);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time for the same reasons as
+/// [`num_nonzero!`].
+///
+/// ```compile_fail
+/// # use word_nums::num_nonzero_unchecked;
+/// let _ = num_nonzero_unchecked!(zero);
+/// ```
+#[proc_macro]
+pub fn num_nonzero_unchecked(token_stream: TokenStream) -> TokenStream {
+    nonzero::expand(token_stream, nonzero::Construction::Unchecked).unwrap_or_else(compile_error)
+}
 
-            sum += acc;
-            if matches!(sign, Sign::Negative) {
-                sum = -sum;
-            }
+/// Specifies a number using English words and wraps it in a tuple struct.
+///
+/// The macro takes the path of the wrapper, a colon and then the number,
+/// just like it would be given to [`num!`]. This is handy for newtypes that
+/// represent units:
+///
+/// ```
+/// # use word_nums::num_wrap;
+/// #[derive(Debug, PartialEq)]
+/// struct Meters(u32);
+///
+/// assert_eq!(num_wrap!(Meters: u32: forty two), Meters(42));
+/// assert_eq!(num_wrap!(Some: three hundred), Some(300_i16));
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The path or the colon after it is missing
+///   * The number could not be passed to [`num!`]
+///
+/// ```compile_fail
+/// # use word_nums::num_wrap;
+/// let _ = num_wrap!(forty two);
+/// ```
+#[proc_macro]
+pub fn num_wrap(token_stream: TokenStream) -> TokenStream {
+    wrap::expand(token_stream).unwrap_or_else(compile_error)
+}
 
-            let literal = make_sized_num_literal(sign, sum);
+/// The documentation that all of the overflow macros share
+macro_rules! overflow_doc {
+    () => {
+        "\n\
+There is a `num_{type}_saturating!` and a `num_{type}_wrapping!` macro for\n\
+every fixed-size integer type, like `num_u8_saturating!` and\n\
+`num_i64_wrapping!`. They accept the same options as [`num!`], except for an\n\
+integer type option.\n\
+\n\
+```\n\
+# use word_nums::{num_i8_wrapping, num_u8_saturating};\n\
+assert_eq!(num_u8_saturating!(one thousand), 255_u8);\n\
+assert_eq!(num_u8_saturating!(minus five), 0_u8);\n\
+assert_eq!(num_i8_wrapping!(one hundred twenty eight), -128_i8);\n\
+```\n\
+\n\
+# Panics\n\
+\n\
+This macro will panic at compile time if:\n\
+  * The number literal is invalid or could not be parsed\n\
+  * The number has a fractional part\n\
+  * An integer type option is given"
+    };
+}
 
-            let mut out = TokenStream::new();
-            out.extend([TokenTree::Literal(literal)]);
-            out
+/// Generates a saturating and a wrapping macro for each of the given types, like
+/// `num_u8_saturating!` and `num_u8_wrapping!`
+macro_rules! overflow_macros {
+    ($(($ty:ident, $int_type:ident, $saturating:ident, $wrapping:ident),)*) => {$(
+        #[doc = concat!(
+            "Specifies a `", stringify!($ty), "` using English words, clamping ",
+            "numbers outside of its range to its nearest bound.\n\n",
+            "See [`", stringify!($wrapping), "!`] for the wrapping counterpart.",
+        )]
+        #[doc = overflow_doc!()]
+        #[proc_macro]
+        pub fn $saturating(token_stream: TokenStream) -> TokenStream {
+            overflow::expand(token_stream, IntType::$int_type, overflow::Overflow::Saturating)
+                .unwrap_or_else(compile_error)
         }
 
-        Err(err) => {
-            let (err_str, span) = match err {
-                NumTokenParseError::NonIdentToken(tt) => ("Non-identifier encountered", tt.span()),
-                NumTokenParseError::InvalidToken(ident) => {
-                    ("Invalid token encountered", ident.span())
-                }
-                NumTokenParseError::UnexpectedSign(ident) => {
-                    ("Unexpected sign descriptor encountered", ident.span())
-                }
-            };
+        #[doc = concat!(
+            "Specifies a `", stringify!($ty), "` using English words, wrapping ",
+            "numbers outside of its range around like an `as` cast does.\n\n",
+            "See [`", stringify!($saturating), "!`] for the saturating counterpart.",
+        )]
+        #[doc = overflow_doc!()]
+        #[proc_macro]
+        pub fn $wrapping(token_stream: TokenStream) -> TokenStream {
+            overflow::expand(token_stream, IntType::$int_type, overflow::Overflow::Wrapping)
+                .unwrap_or_else(compile_error)
+        }
+    )*};
+}
+
+// The pointer-sized types are missing, since their bounds depend on the target
+overflow_macros! {
+    (i8, I8, num_i8_saturating, num_i8_wrapping),
+    (i16, I16, num_i16_saturating, num_i16_wrapping),
+    (i32, I32, num_i32_saturating, num_i32_wrapping),
+    (i64, I64, num_i64_saturating, num_i64_wrapping),
+    (i128, I128, num_i128_saturating, num_i128_wrapping),
+    (u8, U8, num_u8_saturating, num_u8_wrapping),
+    (u16, U16, num_u16_saturating, num_u16_wrapping),
+    (u32, U32, num_u32_saturating, num_u32_wrapping),
+    (u64, U64, num_u64_saturating, num_u64_wrapping),
+    (u128, U128, num_u128_saturating, num_u128_wrapping),
+}
+
+/// Declares constants that are written in English words.
+///
+/// Each item consists of a name, an optional integer type and the number,
+/// separated by an equals sign. Without a type, the constant gets the same
+/// type the literal from [`num!`] would have. Items are separated by
+/// semicolons and may start with a visibility.
+///
+/// ```
+/// # use word_nums::word_consts;
+/// word_consts! {
+///     ANSWER = forty two;
+///     pub LIMIT: u32 = one thousand;
+///     FREEZING = minus forty;
+/// }
+///
+/// assert_eq!(ANSWER, 42_i8);
+/// assert_eq!(LIMIT, 1000_u32);
+/// assert_eq!(FREEZING, -40_i8);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * An item is missing its name or the equals sign
+///   * The type is not an integer type
+///   * Any of the numbers could not be passed to [`num!`]
+///   * Both a type and a type option are given
+///
+/// ```compile_fail
+/// # use word_nums::word_consts;
+/// word_consts! { ANSWER: u8 = i16: forty two; }
+/// ```
+#[proc_macro]
+pub fn word_consts(token_stream: TokenStream) -> TokenStream {
+    consts::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Declares an enum whose variants are named after English number words.
+///
+/// Each variant is named after the words of its number and gets the number
+/// as its discriminant, so "twenty one" becomes `TwentyOne = 21`. Anything
+/// before the name of the enum, like attributes and the visibility, is passed
+/// through and the `enum` keyword is optional. The options of [`num!`] can be
+/// given at the start of the braces.
+///
+/// ```
+/// # use word_nums::number_words_enum;
+/// number_words_enum! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub Small { one, two, three, twenty-one }
+/// }
+///
+/// assert_eq!(Small::One as u8, 1);
+/// assert_eq!(Small::Three as u8, 3);
+/// assert_eq!(Small::TwentyOne as u8, 21);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * The name or the braces are missing
+///   * Any of the numbers is empty, has a fractional part or could not be
+///     passed to [`num!`]
+///   * Two variants have the same name or the same value
+///   * An integer type option is given
+///
+/// ```compile_fail
+/// # use word_nums::number_words_enum;
+/// number_words_enum! { Small { one, two, one } }
+/// ```
+#[proc_macro]
+pub fn number_words_enum(token_stream: TokenStream) -> TokenStream {
+    enums::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies a sorted array of unique integers using English words.
+///
+/// The numbers are separated by semicolons. Just like with [`num_grid!`], all
+/// elements share the smallest integer type that every number fits into,
+/// unless a type option is given. A number that occurs more than once is an
+/// error, unless the leading `dedup:` option is given, which removes the
+/// duplicates instead.
+///
+/// ```
+/// # use word_nums::num_set;
+/// assert_eq!(num_set!(three; one; two), [1_i8, 2, 3]);
+/// assert_eq!(num_set!(dedup: one; two; two; three), [1_i8, 2, 3]);
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Any of the numbers is invalid or could not be parsed
+///   * Any of the numbers is empty or has a fractional part
+///   * A number occurs more than once without the `dedup:` option
+///
+/// ```compile_fail
+/// # use word_nums::num_set;
+/// let set = num_set!(one; two; two; three);
+/// ```
+#[proc_macro]
+pub fn num_set(token_stream: TokenStream) -> TokenStream {
+    set::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+/// Specifies an array of `(key, value)` pairs with keys in English words.
+///
+/// The entries are separated by commas, and each key is followed by `=>` and
+/// its value, which can be any expression. The entries keep their order, so
+/// the array can be used to build a map or be searched directly. Just like
+/// with [`num_set!`], all keys share the smallest integer type that every key
+/// fits into, unless a type option is given.
+///
+/// ```
+/// # use word_nums::num_table;
+/// use std::collections::HashMap;
+///
+/// let table = num_table! { one => "a", two => "b" };
+/// assert_eq!(table, [(1_i8, "a"), (2_i8, "b")]);
+///
+/// let map: HashMap<u16, &str> = num_table!(u16: one hundred => "c", ten => "d").into();
+/// assert_eq!(map[&100], "c");
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+///   * Any of the keys is invalid, empty or could not be parsed
+///   * Any of the keys has a fractional part
+///   * An entry is missing its `=>` or its value
+///   * A key occurs more than once
+///
+/// ```compile_fail
+/// # use word_nums::num_table;
+/// let table = num_table! { one => "a", one => "b" };
+/// ```
+#[proc_macro]
+pub fn num_table(token_stream: TokenStream) -> TokenStream {
+    table::expand(token_stream).unwrap_or_else(compile_error)
+}
+
+fn evaluate(token_stream: TokenStream) -> Result<Number, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+    evaluate_tokens(tokens, options)
+}
+
+fn evaluate_tokens(tokens: Vec<TokenTree>, options: Options) -> Result<Number, NumTokenParseError> {
+    let mut texts = Vec::with_capacity(tokens.len());
+    collect_texts(tokens, options, &mut texts)?;
+
+    let tokens: Vec<Token> = texts
+        .iter()
+        .map(|(_, text, is_literal)| {
+            if *is_literal {
+                Token::Literal(text)
+            } else {
+                Token::Word(text)
+            }
+        })
+        .collect();
 
-            let compile_err = format!(r#"compile_error!("{err_str}")"#)
-                .parse()
-                .expect("Failed to output compile error");
-            attach_span(compile_err, span)
+    // A multiplier that does not fit into the requested type on its own makes
+    // for a clearer error than the whole number not fitting
+    if let Some(int_type) = options.int_type {
+        if let Some((index, value)) = word_nums_core::largest_multiplier(&tokens, options.parse) {
+            if !int_type.contains(value) {
+                let (span, word, _) = &texts[index];
+                return Err(NumTokenParseError::MultiplierOutOfRange(
+                    *span,
+                    word.clone(),
+                    int_type,
+                ));
+            }
         }
     }
-}
 
-fn parse_tokens(token_stream: TokenStream) -> Result<Vec<NumToken>, NumTokenParseError> {
-    let stream_iter = token_stream.into_iter();
+    let parsed = word_nums_core::parse_tokens(&tokens, options.parse).map_err(|err| {
+        // Every macro reports a missing number the same way
+        if err == ParseError::Empty {
+            return NumTokenParseError::EmptyNumber;
+        }
+        let span = err
+            .index()
+            .map_or_else(Span::call_site, |index| texts[index].0);
+        NumTokenParseError::Parse(err, span)
+    })?;
+
+    #[cfg(feature = "diagnostics")]
+    diagnostics::note_scale(&texts, &tokens, options);
 
-    let mut num_tokens = stream_iter
-        .size_hint()
-        .1
-        .map_or_else(Vec::new, Vec::with_capacity);
+    Ok(Number { options, parsed })
+}
 
-    let mut first = true;
-    for token in stream_iter {
+/// Collects the text of every token along with its span and whether it is a
+/// literal
+fn collect_texts(
+    tokens: impl IntoIterator<Item = TokenTree>,
+    options: Options,
+    texts: &mut Vec<(Span, String, bool)>,
+) -> Result<(), NumTokenParseError> {
+    for (i, token) in tokens.into_iter().enumerate() {
         match token {
-            TokenTree::Ident(ident) => {
-                if let Some(parsed_token) = parse_single_token(&ident)? {
-                    // Error if we encounter a sign that is not in the first position
-                    if matches!(parsed_token, NumToken::Sign(_)) && !first {
-                        return Err(NumTokenParseError::UnexpectedSign(ident));
-                    }
+            TokenTree::Ident(ident) => texts.push((ident.span(), ident.to_string(), false)),
+            TokenTree::Literal(literal) => {
+                let text = literal.to_string();
 
-                    num_tokens.push(parsed_token);
-                    first = false;
+                // The words of a string literal are read in place, as if they
+                // had been written out between the surrounding tokens
+                match text.strip_prefix('"').and_then(|x| x.strip_suffix('"')) {
+                    Some(content) => {
+                        // A dash at the start of the number is a minus sign, just
+                        // like a dash token in front of it
+                        let content = match content.trim_start().strip_prefix('-') {
+                            Some(rest) if i == 0 && texts.is_empty() => {
+                                texts.push((literal.span(), "minus".to_string(), false));
+                                rest
+                            }
+                            _ => content,
+                        };
+                        texts.extend(word_nums_core::tokenize(content).into_iter().map(|token| {
+                            match token {
+                                Token::Word(word) => (literal.span(), word.to_string(), false),
+                                Token::Literal(text) => (literal.span(), text.to_string(), true),
+                            }
+                        }));
+                    }
+                    None => texts.push((literal.span(), text, true)),
                 }
             }
 
-            // We just ignore dashes, since they can occur in numbers like twenty-five
+            // A dash in front of the number is a minus sign, just like in "-5"
+            TokenTree::Punct(punct) if i == 0 && punct.as_char() == '-' => {
+                texts.push((punct.span(), "minus".to_string(), false));
+            }
+
+            // We just ignore all other dashes, since they can occur in numbers
+            // like twenty-five
             TokenTree::Punct(punct) if punct.as_char() == '-' => {}
 
-            _ => return Err(NumTokenParseError::NonIdentToken(token)),
+            // In arithmetic, parentheses group parts of the expression. They
+            // are passed on as words, so that each keeps the span of its group
+            TokenTree::Group(group)
+                if options.parse.arith && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                texts.push((group.span_open(), "(".to_string(), false));
+                collect_texts(group.stream(), options, texts)?;
+                texts.push((group.span_close(), ")".to_string(), false));
+            }
+
+            // Groups are not numbers, but a sign inside of one gets its own error,
+            // since its scope would be ambiguous in "(minus five) hundred"
+            TokenTree::Group(ref group) => {
+                return Err(find_grouped_sign(group, options.parse.language)
+                    .map_or(NumTokenParseError::NonIdentToken(token), |span| {
+                        NumTokenParseError::SignInGroup(span)
+                    }));
+            }
+
+            TokenTree::Punct(_) => return Err(NumTokenParseError::NonIdentToken(token)),
+        }
+    }
+
+    Ok(())
+}
+
+fn find_grouped_sign(group: &Group, language: Language) -> Option<Span> {
+    group.stream().into_iter().find_map(|token| match token {
+        TokenTree::Ident(ident) => language
+            .parse_sign(&ident.to_string().to_lowercase())
+            .map(|_| ident.span()),
+        TokenTree::Group(group) => find_grouped_sign(&group, language),
+        _ => None,
+    })
+}
+
+fn make_literal(number: Number) -> Result<Literal, NumTokenParseError> {
+    let parsed = number.parsed;
+    if parsed.fraction.is_some() {
+        if number.options.int_type.is_some() || number.options.radix != Radix::Decimal {
+            return Err(NumTokenParseError::FractionalInteger);
         }
+
+        // The digits are formatted exactly, so no precision is lost to an
+        // intermediate float
+        return Ok(format!("{parsed}f64")
+            .parse()
+            .expect("Failed to output float literal"));
     }
 
-    Ok(num_tokens)
-}
-
-fn parse_single_token(ident: &Ident) -> Result<Option<NumToken>, NumTokenParseError> {
-    match ident.to_string().to_lowercase().as_str() {
-        "zero" => Ok(Some(NumToken::Literal(0))),
-        "one" | "a" => Ok(Some(NumToken::Literal(1))),
-        "two" => Ok(Some(NumToken::Literal(2))),
-        "three" => Ok(Some(NumToken::Literal(3))),
-        "four" => Ok(Some(NumToken::Literal(4))),
-        "five" => Ok(Some(NumToken::Literal(5))),
-        "six" => Ok(Some(NumToken::Literal(6))),
-        "seven" => Ok(Some(NumToken::Literal(7))),
-        "eight" => Ok(Some(NumToken::Literal(8))),
-        "nine" => Ok(Some(NumToken::Literal(9))),
-        "ten" => Ok(Some(NumToken::Literal(10))),
-        "eleven" => Ok(Some(NumToken::Literal(11))),
-        "twelve" => Ok(Some(NumToken::Literal(12))),
-        "thirteen" => Ok(Some(NumToken::Literal(13))),
-        "fourteen" => Ok(Some(NumToken::Literal(14))),
-        "fifteen" => Ok(Some(NumToken::Literal(15))),
-        "sixteen" => Ok(Some(NumToken::Literal(16))),
-        "seventeen" => Ok(Some(NumToken::Literal(17))),
-        "eighteen" => Ok(Some(NumToken::Literal(18))),
-        "nineteen" => Ok(Some(NumToken::Literal(19))),
-
-        "twenty" => Ok(Some(NumToken::Literal(20))),
-        "thirty" => Ok(Some(NumToken::Literal(30))),
-        "forty" | "fourty" => Ok(Some(NumToken::Literal(40))),
-        "fifty" => Ok(Some(NumToken::Literal(50))),
-        "sixty" => Ok(Some(NumToken::Literal(60))),
-        "seventy" => Ok(Some(NumToken::Literal(70))),
-        "eighty" => Ok(Some(NumToken::Literal(80))),
-        "ninety" => Ok(Some(NumToken::Literal(90))),
-
-        "hundred" => Ok(Some(NumToken::Multiplier(100))),
-        "thousand" => Ok(Some(NumToken::Multiplier(1000))),
-        "million" => Ok(Some(NumToken::Multiplier(1_000_000))),
-        "billion" => Ok(Some(NumToken::Multiplier(1_000_000_000))),
-        "trillion" => Ok(Some(NumToken::Multiplier(1_000_000_000_000))),
-        "quadrillion" => Ok(Some(NumToken::Multiplier(1_000_000_000_000_000))),
-        "quintillion" => Ok(Some(NumToken::Multiplier(1_000_000_000_000_000_000))),
-        "septillion" => Ok(Some(NumToken::Multiplier(1_000_000_000_000_000_000_000))),
-        "octillion" => Ok(Some(NumToken::Multiplier(
-            1_000_000_000_000_000_000_000_000,
-        ))),
-
-        "plus" | "positive" => Ok(Some(NumToken::Sign(Sign::Positive))),
-        "minus" | "negative" => Ok(Some(NumToken::Sign(Sign::Negative))),
-
-        "and" => Ok(None),
-
-        _ => Err(NumTokenParseError::InvalidToken(ident.clone())),
+    match (number.options.int_type, number.options.radix) {
+        (None, Radix::Decimal) => Ok(make_sized_num_literal(parsed.sign, parsed.value)),
+        (int_type, radix) => {
+            let int_type = int_type.unwrap_or_else(|| sized_int_type(parsed.sign, parsed.value));
+            int_type::radix_literal(int_type, parsed.value, radix)
+                .ok_or(NumTokenParseError::OutOfRange)
+        }
     }
 }
 
-macro_rules! return_if_ok {
-    ($e:expr) => {
-        if let Ok(x) = $e {
-            return x;
+fn literal_stream(literal: Literal) -> TokenStream {
+    let mut out = TokenStream::new();
+    out.extend([TokenTree::Literal(literal)]);
+    out
+}
+
+// Every error gets its own message, so this grows with the errors
+#[allow(clippy::too_many_lines)]
+fn compile_error(err: NumTokenParseError) -> TokenStream {
+    let (err_str, span): (Cow<str>, Span) = match err {
+        NumTokenParseError::NonIdentToken(tt) => (
+            format!("Unexpected token '{tt}', expected a number word").into(),
+            tt.span(),
+        ),
+        NumTokenParseError::Parse(err, span) => (err.to_string().into(), span),
+        NumTokenParseError::MisplacedOption(ident) => (
+            format!("Options like '{ident}:' have to come before the number").into(),
+            ident.span(),
+        ),
+        NumTokenParseError::UnknownOption(ident) => {
+            ("Unknown option encountered".into(), ident.span())
+        }
+        NumTokenParseError::OutOfRange => (
+            "Number does not fit into the requested type".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::MultiplierOutOfRange(span, word, int_type) => (
+            format!(
+                "Number does not fit into the requested type, '{word}' alone is larger than {}::MAX",
+                int_type.name()
+            )
+            .into(),
+            span,
+        ),
+        NumTokenParseError::UnexpectedIntType => (
+            "An integer type can not be specified here".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::FractionalInteger => (
+            "A fractional number can not be an integer".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::RaggedRow(span) => (
+            "All rows need to have the same number of elements".into(),
+            span,
+        ),
+        NumTokenParseError::EmptyNumber => ("Expected a number".into(), Span::call_site()),
+        NumTokenParseError::InvalidConst(span) => {
+            ("Expected a constant like 'NAME = forty two'".into(), span)
+        }
+        NumTokenParseError::InvalidEnum(span) => (
+            "Expected an enum like 'Small { one, two, three }'".into(),
+            span,
+        ),
+        NumTokenParseError::InvalidVariant(span) => (
+            "The words of the number do not make up a valid variant name".into(),
+            span,
+        ),
+        NumTokenParseError::DuplicateVariant(span) => {
+            ("Two variants have the same name".into(), span)
+        }
+        NumTokenParseError::DuplicateDiscriminant(span) => {
+            ("Two variants have the same value".into(), span)
+        }
+        NumTokenParseError::UnknownType(ident) => ("Expected an integer type".into(), ident.span()),
+        NumTokenParseError::NegativeComponent(span) => {
+            ("A component of a dotted number can not be negative".into(), span)
+        }
+        NumTokenParseError::InvalidPercent(span) => (
+            "Expected a percentage like 'fifty percent' or 'fifty percent of ten'".into(),
+            span,
+        ),
+        NumTokenParseError::InvalidSuffix(span) => {
+            ("Expected a suffix like 'suffix = \"f32\":'".into(), span)
+        }
+        NumTokenParseError::DuplicateElement(span) => ("Duplicate number in set".into(), span),
+        NumTokenParseError::DuplicateKey(span) => ("Duplicate key in table".into(), span),
+        NumTokenParseError::InvalidTableEntry(span) => (
+            "Expected an entry like 'one => value'".into(),
+            span,
+        ),
+        NumTokenParseError::MissingPath => (
+            "Expected a path to wrap the number in, like 'Meters: forty two'".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::NegativeLength => {
+            ("Lengths can not be negative".into(), Span::call_site())
+        }
+        NumTokenParseError::SignInGroup(span) => (
+            "Signs are only allowed at the start of the whole number, not inside of a group".into(),
+            span,
+        ),
+        NumTokenParseError::MissingUnit(span) => (
+            "Expected a unit like 'seconds' after the number".into(),
+            span,
+        ),
+        NumTokenParseError::DuplicateUnit(span) => ("Duplicate unit in duration".into(), span),
+        NumTokenParseError::InvalidRatio(span) => (
+            "Expected a ratio like 'fifty per hundred' or 'three out of four'".into(),
+            span,
+        ),
+        NumTokenParseError::InvalidPower(span) => (
+            "Expected a power like 'two to the eighth'".into(),
+            span,
+        ),
+        NumTokenParseError::InvalidEq(span) => (
+            "Expected two numbers separated by a semicolon".into(),
+            span,
+        ),
+        NumTokenParseError::NotEqual(span, left, right) => (
+            format!("'{left}' is not equal to '{right}'").into(),
+            span,
+        ),
+        NumTokenParseError::InvalidInRange(span) => (
+            "Expected a type and a number like 'u8, forty two'".into(),
+            span,
+        ),
+        NumTokenParseError::NotInRange(span, value, int_type) => (
+            format!("{value} does not fit into {}", int_type.name()).into(),
+            span,
+        ),
+        NumTokenParseError::InvalidRange(span) => {
+            ("Expected a range like 'one to ten'".into(), span)
         }
+        NumTokenParseError::DescendingRange(span) => (
+            "The end of a range can not be smaller than its start".into(),
+            span,
+        ),
+        NumTokenParseError::RadixWithSuffix => (
+            "A radix option like 'hex_lit:' can not be combined with 'suffix ='".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::PointerSizedBits => (
+            "The number of bits of isize and usize depends on the target".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::InexactFloat => (
+            "The number can not be represented exactly as an f16".into(),
+            Span::call_site(),
+        ),
+        NumTokenParseError::ZeroNonZero => {
+            ("A NonZero number can not be zero".into(), Span::call_site())
+        }
+        NumTokenParseError::ZeroDenominator(span) => {
+            ("The denominator of a ratio can not be zero".into(), span)
+        }
+        NumTokenParseError::InvalidCents(span) => (
+            "The cents must be a whole number from zero to ninety nine".into(),
+            span,
+        ),
+        NumTokenParseError::InvalidDate(span) => (
+            "Expected a date like 'two thousand twenty four; three; fifteen'".into(),
+            span,
+        ),
+        NumTokenParseError::DateOutOfRange(span, message)
+        | NumTokenParseError::TimeOutOfRange(span, message) => (message.into(), span),
+        NumTokenParseError::InvalidTime(span) => (
+            "Expected a time like 'fourteen; thirty' or 'half past noon'".into(),
+            span,
+        ),
+        NumTokenParseError::MissingType(span) => {
+            ("Expected the name of an integer type".into(), span)
+        }
+        NumTokenParseError::InvalidDigit(span) => {
+            ("Expected a single digit from zero to nine".into(), span)
+        }
+        NumTokenParseError::InvalidOctalDigit(span) => {
+            ("Expected a single octal digit from zero to seven".into(), span)
+        }
+        NumTokenParseError::InvalidPermissions(span) => (
+            "Expected a file mode of three or four digits, like 'seven five five'".into(),
+            span,
+        ),
+        NumTokenParseError::NegativeDuration => {
+            ("Durations can not be negative".into(), Span::call_site())
+        }
+        NumTokenParseError::InteriorNul => (
+            "The number can not be written as a C string, because it contains a NUL byte".into(),
+            Span::call_site(),
+        ),
     };
+
+    // The debug representation escapes the message into a valid string literal.
+    // Braces make the error valid in item position as well.
+    let compile_err = format!("compile_error! {{ {err_str:?} }}")
+        .parse()
+        .expect("Failed to output compile error");
+    attach_span(compile_err, span)
 }
 
-fn make_sized_num_literal(sign: Sign, value: NumType) -> Literal {
-    match sign {
-        Sign::Unspecified | Sign::Negative => {
-            return_if_ok!(value.try_into().map(Literal::i8_suffixed));
-            return_if_ok!(value.try_into().map(Literal::i16_suffixed));
-            return_if_ok!(value.try_into().map(Literal::i32_suffixed));
-            return_if_ok!(value.try_into().map(Literal::i64_suffixed));
-            Literal::i128_suffixed(value)
+fn parse_options(
+    token_stream: TokenStream,
+) -> Result<(Options, Vec<TokenTree>), NumTokenParseError> {
+    let mut tokens: Vec<TokenTree> = token_stream.into_iter().collect();
+    let mut options = Options::default();
+
+    // Options are given as a prefix of identifiers, each followed by a single colon
+    let mut prefix_len = 0;
+    while let [TokenTree::Ident(ident), TokenTree::Punct(punct), ..] = &tokens[prefix_len..] {
+        if punct.as_char() != ':' || punct.spacing() != Spacing::Alone {
+            break;
         }
-        Sign::Positive => {
-            return_if_ok!(value.try_into().map(Literal::u8_suffixed));
-            return_if_ok!(value.try_into().map(Literal::u16_suffixed));
-            return_if_ok!(value.try_into().map(Literal::u32_suffixed));
-            return_if_ok!(value.try_into().map(Literal::u64_suffixed));
-
-            // There is no way to avoid potentially truncating the value here and still
-            // support signed number literals. This library is intended for integer literals
-            // only,so we won't depend on a bignum library for the internal representation
-            // of the numbers.
-            Literal::u128_suffixed(value.try_into().expect(NUM_TOO_BIG_ERROR_MSG))
+
+        let name = ident.to_string();
+        match name.as_str() {
+            "loose" => options.parse.loose = true,
+            "approx" => options.parse.approx = true,
+            "digits" => options.parse.digits = true,
+            "folksy" => options.parse.folksy = true,
+            "arith" => options.parse.arith = true,
+            "ordinal" => options.parse.ordinal = true,
+            "alias" => options.parse.alias = true,
+            "prog" => options.parse.prog = true,
+            "strict" => options.parse.strict = true,
+            "roman" => options.parse.roman = true,
+            "hex" => options.parse.hex = true,
+            "fractions" => options.parse.fractions = true,
+            "hex_lit" => options.radix = Radix::Hexadecimal,
+            "oct_lit" => options.radix = Radix::Octal,
+            "bin_lit" => options.radix = Radix::Binary,
+            "pl" => options.parse.language = Language::Polish,
+            "ko" => options.parse.language = Language::Korean,
+            "tr" => options.parse.language = Language::Turkish,
+            "el" => options.parse.language = Language::Greek,
+            "he" => options.parse.language = Language::Hebrew,
+            "de" => options.parse.language = Language::German,
+            "fr" => options.parse.language = Language::French,
+            "sv" => options.parse.language = Language::Swedish,
+            "vi" => options.parse.language = Language::Vietnamese,
+            "fi" => options.parse.language = Language::Finnish,
+            "gb" => options.parse.dialect = Dialect::British,
+            "us" => options.parse.dialect = Dialect::American,
+            "detect" => options.parse.dialect = Dialect::Detect,
+            "short" => {
+                options.parse.scale = Scale::Short;
+                options.explicit_scale = true;
+            }
+            "long" => {
+                options.parse.scale = Scale::Long;
+                options.explicit_scale = true;
+            }
+            _ => {
+                options.int_type = Some(
+                    IntType::from_name(&name)
+                        .ok_or_else(|| NumTokenParseError::UnknownOption(ident.clone()))?,
+                );
+            }
         }
+        prefix_len += 2;
     }
-}
+    tokens.drain(..prefix_len);
 
-fn get_sign(num_tokens: &mut Vec<NumToken>) -> Sign {
-    if let Some(NumToken::Sign(sign)) = num_tokens.first().copied() {
-        num_tokens.remove(0);
-        sign
-    } else {
-        Sign::Unspecified
+    // An option after the first word would otherwise be reported as a stray
+    // colon, or worse, as part of the number
+    if let Some(ident) = tokens.windows(2).find_map(|pair| match pair {
+        [TokenTree::Ident(ident), TokenTree::Punct(punct)]
+            if punct.as_char() == ':' && punct.spacing() == Spacing::Alone =>
+        {
+            Some(ident)
+        }
+        _ => None,
+    }) {
+        return Err(NumTokenParseError::MisplacedOption(ident.clone()));
     }
+
+    Ok((options, tokens))
 }
 
-const fn is_larger_multiplier(x: NumToken, than: NumType) -> bool {
-    if let NumToken::Multiplier(value) = x {
-        value > than
+const fn bit_width(value: NumType) -> usize {
+    if value < 0 {
+        (NumType::BITS - (!value).leading_zeros() + 1) as usize
     } else {
-        false
+        (NumType::BITS - value.leading_zeros()) as usize
     }
 }
 
+fn make_sized_num_literal(sign: Sign, value: NumType) -> Literal {
+    // There is no way to avoid potentially truncating the value here and still
+    // support signed number literals. This library is intended for integer literals
+    // only,so we won't depend on a bignum library for the internal representation
+    // of the numbers.
+    int_type::int_literal(sized_int_type(sign, value), value).expect(NUM_TOO_BIG_ERROR_MSG)
+}
+
+fn sized_int_type(sign: Sign, value: NumType) -> IntType {
+    IntType::for_value(matches!(sign, Sign::Positive), value).expect(NUM_TOO_BIG_ERROR_MSG)
+}
+
 fn attach_span(token_stream: TokenStream, span: Span) -> TokenStream {
     let mut ret = TokenStream::new();
     ret.extend(token_stream.into_iter().map(|token| {
@@ -258,23 +2069,74 @@ fn attach_span(token_stream: TokenStream, span: Span) -> TokenStream {
     ret
 }
 
-#[derive(Debug, Copy, Clone)]
-enum NumToken {
-    Literal(NumType),
-    Multiplier(NumType),
-    Sign(Sign),
+#[derive(Debug, Copy, Clone, Default)]
+struct Options {
+    parse: word_nums_core::Options,
+    int_type: Option<IntType>,
+    radix: Radix,
+
+    /// Whether the scale was given as an option instead of being the default
+    explicit_scale: bool,
 }
 
 #[derive(Debug, Copy, Clone)]
-enum Sign {
-    Unspecified,
-    Positive,
-    Negative,
+struct Number {
+    options: Options,
+    parsed: word_nums_core::Number,
 }
 
 #[derive(Debug)]
 enum NumTokenParseError {
     NonIdentToken(TokenTree),
-    InvalidToken(Ident),
-    UnexpectedSign(Ident),
+    Parse(ParseError, Span),
+    UnknownOption(Ident),
+    MisplacedOption(Ident),
+    OutOfRange,
+    MultiplierOutOfRange(Span, String, IntType),
+    UnexpectedIntType,
+    FractionalInteger,
+    RaggedRow(Span),
+    EmptyNumber,
+    NegativeLength,
+    MissingPath,
+    InvalidPercent(Span),
+    NegativeComponent(Span),
+    InvalidSuffix(Span),
+    DuplicateElement(Span),
+    DuplicateKey(Span),
+    InvalidTableEntry(Span),
+    InvalidConst(Span),
+    InvalidEnum(Span),
+    InvalidVariant(Span),
+    DuplicateVariant(Span),
+    DuplicateDiscriminant(Span),
+    UnknownType(Ident),
+    InteriorNul,
+    SignInGroup(Span),
+    MissingUnit(Span),
+    DuplicateUnit(Span),
+    NegativeDuration,
+    InvalidDigit(Span),
+    InvalidOctalDigit(Span),
+    InvalidPermissions(Span),
+    InvalidCents(Span),
+    InvalidRatio(Span),
+    ZeroDenominator(Span),
+    InvalidEq(Span),
+    NotEqual(Span, String, String),
+    InvalidPower(Span),
+    InvalidDate(Span),
+    DateOutOfRange(Span, &'static str),
+    InvalidTime(Span),
+    ZeroNonZero,
+    InvalidRange(Span),
+    DescendingRange(Span),
+    TimeOutOfRange(Span, &'static str),
+    MissingType(Span),
+    InvalidInRange(Span),
+    NotInRange(Span, NumType, IntType),
+    #[cfg_attr(not(feature = "f16"), allow(dead_code))]
+    InexactFloat,
+    PointerSizedBits,
+    RadixWithSuffix,
 }
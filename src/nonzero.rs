@@ -0,0 +1,36 @@
+use proc_macro::TokenStream;
+
+use crate::{evaluate, make_literal, NumTokenParseError};
+
+/// How the `NonZero` is constructed from the literal
+#[derive(Debug, Copy, Clone)]
+pub enum Construction {
+    /// `NonZero::new(..).unwrap()`, which can be used in constants
+    Checked,
+
+    /// `NonZero::new_unchecked(..)` in an `unsafe` block
+    Unchecked,
+}
+
+pub fn expand(
+    token_stream: TokenStream,
+    construction: Construction,
+) -> Result<TokenStream, NumTokenParseError> {
+    let number = evaluate(token_stream)?;
+    if number.parsed.fraction.is_some() {
+        return Err(NumTokenParseError::FractionalInteger);
+    }
+    if number.parsed.value == 0 {
+        return Err(NumTokenParseError::ZeroNonZero);
+    }
+    let literal = make_literal(number)?;
+
+    // The zero check above is what makes the unchecked construction sound
+    let code = match construction {
+        Construction::Checked => format!("::core::num::NonZero::new({literal}).unwrap()"),
+        Construction::Unchecked => {
+            format!("unsafe {{ ::core::num::NonZero::new_unchecked({literal}) }}")
+        }
+    };
+    Ok(code.parse().expect("Failed to output NonZero"))
+}
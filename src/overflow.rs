@@ -0,0 +1,63 @@
+use proc_macro::{Literal, TokenStream, TokenTree};
+
+use word_nums_core::IntType;
+
+use crate::{evaluate, NumTokenParseError, NumType};
+
+/// How a number that does not fit into its type is brought into range
+#[derive(Debug, Copy, Clone)]
+pub enum Overflow {
+    /// Clamps the number to the nearest bound of the type
+    Saturating,
+
+    /// Wraps the number around in two's complement, just like `as` does
+    Wrapping,
+}
+
+// Wrapping is exactly what the truncating casts do
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn overflow_literal(int_type: IntType, value: NumType, overflow: Overflow) -> Option<Literal> {
+    macro_rules! convert {
+        ($t:ty, $constructor:ident) => {
+            Literal::$constructor(match overflow {
+                Overflow::Saturating => {
+                    <$t>::try_from(value).unwrap_or(if value < 0 { <$t>::MIN } else { <$t>::MAX })
+                }
+                Overflow::Wrapping => value as $t,
+            })
+        };
+    }
+
+    Some(match int_type {
+        IntType::I8 => convert!(i8, i8_suffixed),
+        IntType::I16 => convert!(i16, i16_suffixed),
+        IntType::I32 => convert!(i32, i32_suffixed),
+        IntType::I64 => convert!(i64, i64_suffixed),
+        IntType::I128 => convert!(i128, i128_suffixed),
+        IntType::U8 => convert!(u8, u8_suffixed),
+        IntType::U16 => convert!(u16, u16_suffixed),
+        IntType::U32 => convert!(u32, u32_suffixed),
+        IntType::U64 => convert!(u64, u64_suffixed),
+        IntType::U128 => convert!(u128, u128_suffixed),
+        // The bounds of the pointer-sized types depend on the target
+        IntType::Isize | IntType::Usize => return None,
+    })
+}
+
+pub fn expand(
+    token_stream: TokenStream,
+    int_type: IntType,
+    overflow: Overflow,
+) -> Result<TokenStream, NumTokenParseError> {
+    let number = evaluate(token_stream)?;
+    if number.options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+    if number.parsed.fraction.is_some() {
+        return Err(NumTokenParseError::FractionalInteger);
+    }
+
+    let literal = overflow_literal(int_type, number.parsed.value, overflow)
+        .expect("Overflow macros are only generated for fixed-size types");
+    Ok(TokenTree::Literal(literal).into())
+}
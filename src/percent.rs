@@ -0,0 +1,100 @@
+use proc_macro::{Span, TokenStream, TokenTree};
+use word_nums_core::{Fraction, Number, ParseError, Sign};
+
+use crate::{evaluate_tokens, make_literal, parse_options, NumTokenParseError, NumType, Options};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, mut tokens) = parse_options(token_stream)?;
+
+    let Some(position) = tokens.iter().position(|x| is_word(x, "percent")) else {
+        return Err(NumTokenParseError::InvalidPercent(Span::call_site()));
+    };
+    let mut rest = tokens.split_off(position).into_iter().skip(1);
+    let (_, numerator, denominator) = evaluate_rational(tokens, options)?;
+    let denominator = denominator.checked_mul(100).ok_or_else(overflow)?;
+
+    let parsed = match rest.next() {
+        // A plain percentage is always a fraction
+        None => Number {
+            sign: if numerator < 0 {
+                Sign::Negative
+            } else {
+                Sign::Unspecified
+            },
+            value: numerator / denominator,
+            fraction: Some(Fraction {
+                numerator: numerator % denominator,
+                denominator,
+            }),
+        },
+
+        // The percentage of a number has to be a whole number
+        Some(token) if is_word(&token, "of") => {
+            let (base_sign, base_numerator, base_denominator) =
+                evaluate_rational(rest.collect(), options)?;
+
+            let numerator = numerator.checked_mul(base_numerator).ok_or_else(overflow)?;
+            let denominator = denominator
+                .checked_mul(base_denominator)
+                .ok_or_else(overflow)?;
+            if numerator % denominator != 0 {
+                return Err(NumTokenParseError::Parse(
+                    ParseError::NotWhole,
+                    Span::call_site(),
+                ));
+            }
+
+            let value = numerator / denominator;
+            Number {
+                sign: match base_sign {
+                    _ if value < 0 => Sign::Negative,
+                    Sign::Positive => Sign::Positive,
+                    _ => Sign::Unspecified,
+                },
+                value,
+                fraction: None,
+            }
+        }
+
+        Some(token) => return Err(NumTokenParseError::InvalidPercent(token.span())),
+    };
+
+    let literal = make_literal(crate::Number { options, parsed })?;
+    Ok(TokenTree::Literal(literal).into())
+}
+
+/// Evaluates the tokens into their sign, a signed numerator and a denominator
+pub fn evaluate_rational(
+    tokens: Vec<TokenTree>,
+    options: Options,
+) -> Result<(Sign, NumType, NumType), NumTokenParseError> {
+    if tokens.is_empty() {
+        return Err(NumTokenParseError::EmptyNumber);
+    }
+
+    let parsed = evaluate_tokens(tokens, options)?.parsed;
+    let Some(fraction) = parsed.fraction else {
+        return Ok((parsed.sign, parsed.value, 1));
+    };
+
+    // The fraction keeps its sign separately, since the whole part may be zero
+    let numerator = if matches!(parsed.sign, Sign::Negative) {
+        -fraction.numerator.abs()
+    } else {
+        fraction.numerator
+    };
+    let numerator = parsed
+        .value
+        .checked_mul(fraction.denominator)
+        .and_then(|x| x.checked_add(numerator))
+        .ok_or_else(overflow)?;
+    Ok((parsed.sign, numerator, fraction.denominator))
+}
+
+fn is_word(token: &TokenTree, word: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string().eq_ignore_ascii_case(word))
+}
+
+fn overflow() -> NumTokenParseError {
+    NumTokenParseError::Parse(ParseError::Overflow, Span::call_site())
+}
@@ -0,0 +1,43 @@
+use proc_macro::{Span, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::int_type::{self, Radix};
+use crate::{evaluate_tokens, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // Just like with num_char_array!, every word is a digit of its own
+    let mut mode = 0;
+    let mut digits = 0;
+    for token in tokens {
+        if matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '-') {
+            continue;
+        }
+
+        let span = token.span();
+        let number = evaluate_tokens(vec![token], options)?;
+        let digit = Some(number.parsed.value)
+            .filter(|x| (0..8).contains(x))
+            .filter(|_| {
+                number.parsed.fraction.is_none() && matches!(number.parsed.sign, Sign::Unspecified)
+            })
+            .ok_or(NumTokenParseError::InvalidOctalDigit(span))?;
+        mode = mode * 8 + digit;
+        digits += 1;
+    }
+
+    // A mode is the three permission digits, optionally with the special bits
+    // like setuid in front of them
+    if !(3..=4).contains(&digits) {
+        return Err(NumTokenParseError::InvalidPermissions(Span::call_site()));
+    }
+
+    let literal = int_type::radix_literal(IntType::U32, mode, Radix::Octal)
+        .ok_or(NumTokenParseError::OutOfRange)?;
+    Ok(TokenTree::Literal(literal).into())
+}
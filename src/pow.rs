@@ -0,0 +1,48 @@
+use proc_macro::{Span, TokenStream, TokenTree};
+
+use word_nums_core::{ParseError, Sign};
+
+use crate::{grid, make_literal, parse_options, NumTokenParseError, Number};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, mut tokens) = parse_options(token_stream)?;
+
+    // The exponent follows the first "to", as in "two to the eighth"
+    let Some(position) = tokens.iter().position(|x| is_word(x, "to")) else {
+        return Err(NumTokenParseError::InvalidPower(Span::call_site()));
+    };
+    let exponent_tokens = tokens.split_off(position + 1);
+    let to_span = tokens.pop().map_or_else(Span::call_site, |x| x.span());
+    let exponent_span = exponent_tokens.first().map_or(to_span, TokenTree::span);
+
+    let (base_sign, base) = grid::evaluate_cell(tokens, options)?;
+
+    // The exponent is an ordinal, which may have "the" in front of it
+    let mut exponent_options = options;
+    exponent_options.parse.ordinal = true;
+    let (exponent_sign, exponent) = grid::evaluate_cell(exponent_tokens, exponent_options)?;
+    let exponent = u32::try_from(exponent)
+        .ok()
+        .filter(|_| matches!(exponent_sign, Sign::Unspecified))
+        .ok_or(NumTokenParseError::InvalidPower(exponent_span))?;
+
+    let value = base.checked_pow(exponent).ok_or(NumTokenParseError::Parse(
+        ParseError::Overflow,
+        Span::call_site(),
+    ))?;
+    let sign = if value < 0 { Sign::Negative } else { base_sign };
+
+    let literal = make_literal(Number {
+        options,
+        parsed: word_nums_core::Number {
+            sign,
+            value,
+            fraction: None,
+        },
+    })?;
+    Ok(TokenTree::Literal(literal).into())
+}
+
+fn is_word(token: &TokenTree, word: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string().eq_ignore_ascii_case(word))
+}
@@ -0,0 +1,51 @@
+use proc_macro::{Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{grid, int_type, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, mut tokens) = parse_options(token_stream)?;
+
+    // The end follows the first "to", as in "one to ten"
+    let Some(position) = tokens.iter().position(|x| is_word(x, "to")) else {
+        return Err(NumTokenParseError::InvalidRange(Span::call_site()));
+    };
+    let end_tokens = tokens.split_off(position + 1);
+    let to_span = tokens.pop().map_or_else(Span::call_site, |x| x.span());
+    let span = end_tokens.first().map_or(to_span, TokenTree::span);
+
+    let (start_sign, start) = grid::evaluate_cell(tokens, options)?;
+    let (end_sign, end) = grid::evaluate_cell(end_tokens, options)?;
+    if start > end {
+        return Err(NumTokenParseError::DescendingRange(span));
+    }
+
+    // Just like with num_grid!, the type is only unsigned if both numbers
+    // are explicitly positive
+    let int_type = match options.int_type {
+        Some(int_type) => int_type,
+        None => IntType::smallest_fit(
+            matches!((start_sign, end_sign), (Sign::Positive, Sign::Positive)),
+            [start, end].into_iter(),
+        )
+        .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+
+    let literal = |value| {
+        int_type::radix_literal(int_type, value, options.radix)
+            .ok_or(NumTokenParseError::OutOfRange)
+    };
+    Ok([
+        TokenTree::Literal(literal(start)?),
+        TokenTree::Punct(Punct::new('.', Spacing::Joint)),
+        TokenTree::Punct(Punct::new('.', Spacing::Alone)),
+        TokenTree::Literal(literal(end)?),
+    ]
+    .into_iter()
+    .collect())
+}
+
+fn is_word(token: &TokenTree, word: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string().eq_ignore_ascii_case(word))
+}
@@ -0,0 +1,87 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{grid, int_type, parse_options, NumTokenParseError, NumType};
+
+fn is_word(token: Option<&TokenTree>, word: &str) -> bool {
+    matches!(token, Some(TokenTree::Ident(ident)) if ident.to_string().eq_ignore_ascii_case(word))
+}
+
+/// Returns the number of tokens of the separator starting at the index, which
+/// is either "per", "over" or "out of"
+fn separator_len(tokens: &[TokenTree], index: usize) -> Option<usize> {
+    if is_word(tokens.get(index), "per") || is_word(tokens.get(index), "over") {
+        Some(1)
+    } else if is_word(tokens.get(index), "out") && is_word(tokens.get(index + 1), "of") {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+const fn gcd(mut a: NumType, mut b: NumType) -> NumType {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, mut tokens) = parse_options(token_stream)?;
+
+    let Some((position, len)) =
+        (0..tokens.len()).find_map(|index| separator_len(&tokens, index).map(|len| (index, len)))
+    else {
+        return Err(NumTokenParseError::InvalidRatio(Span::call_site()));
+    };
+    let denominator_tokens = tokens.split_off(position + len);
+    let separator_span = tokens
+        .drain(position..)
+        .next_back()
+        .map_or_else(Span::call_site, |x| x.span());
+    let span = denominator_tokens
+        .first()
+        .map_or(separator_span, TokenTree::span);
+
+    let (numerator_sign, numerator) = grid::evaluate_cell(tokens, options)?;
+    let (denominator_sign, denominator) = grid::evaluate_cell(denominator_tokens, options)?;
+    if denominator == 0 {
+        return Err(NumTokenParseError::ZeroDenominator(span));
+    }
+
+    // The sign is always moved into the numerator, so a zero numerator is
+    // always reduced to zero over one
+    let divisor = gcd(numerator, denominator).abs();
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator / divisor, -denominator / divisor)
+    } else {
+        (numerator / divisor, denominator / divisor)
+    };
+
+    // Just like with num_grid!, the type is only unsigned if both numbers
+    // are explicitly positive
+    let int_type = match options.int_type {
+        Some(int_type) => int_type,
+        None => IntType::smallest_fit(
+            matches!(
+                (numerator_sign, denominator_sign),
+                (Sign::Positive, Sign::Positive)
+            ),
+            [numerator, denominator].into_iter(),
+        )
+        .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+
+    let mut elements_stream = TokenStream::new();
+    for value in [numerator, denominator] {
+        let literal = int_type::radix_literal(int_type, value, options.radix)
+            .ok_or(NumTokenParseError::OutOfRange)?;
+        elements_stream.extend([
+            TokenTree::Literal(literal),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Parenthesis, elements_stream)).into())
+}
@@ -0,0 +1,67 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{grid, int_type, parse_options, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let mut tokens: Vec<TokenTree> = token_stream.into_iter().collect();
+
+    // Removing duplicates is an option of its own, which has to come first
+    let dedup = matches!(
+        &tokens[..],
+        [TokenTree::Ident(ident), TokenTree::Punct(punct), ..]
+            if ident.to_string() == "dedup"
+                && punct.as_char() == ':'
+                && punct.spacing() == Spacing::Alone
+    );
+    if dedup {
+        tokens.drain(..2);
+    }
+
+    let (options, tokens) = parse_options(tokens.into_iter().collect())?;
+    let mut elements = Vec::new();
+    for element in grid::split_punct(tokens, ';') {
+        let span = element
+            .first()
+            .map_or_else(Span::call_site, TokenTree::span);
+        let (sign, value) = grid::evaluate_cell(element, options)?;
+        elements.push((span, sign, value));
+    }
+
+    let int_type = match options.int_type {
+        Some(int_type) => int_type,
+        None => IntType::smallest_fit(
+            elements
+                .iter()
+                .all(|(_, sign, _)| matches!(sign, Sign::Positive)),
+            elements.iter().map(|(_, _, x)| *x),
+        )
+        .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+
+    // The sort is stable, so the first occurrence of a duplicate stays in front
+    elements.sort_by_key(|(_, _, value)| *value);
+    let mut values = Vec::with_capacity(elements.len());
+    for (span, _, value) in elements {
+        if values.last() == Some(&value) {
+            if dedup {
+                continue;
+            }
+            return Err(NumTokenParseError::DuplicateElement(span));
+        }
+        values.push(value);
+    }
+
+    let mut elements_stream = TokenStream::new();
+    for value in values {
+        let literal = int_type::radix_literal(int_type, value, options.radix)
+            .ok_or(NumTokenParseError::OutOfRange)?;
+        elements_stream.extend([
+            TokenTree::Literal(literal),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Bracket, elements_stream)).into())
+}
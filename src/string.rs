@@ -0,0 +1,41 @@
+use std::ffi::CString;
+
+use proc_macro::{Literal, TokenStream, TokenTree};
+
+use crate::{evaluate, int_type::Radix, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let text = format_number(token_stream)?;
+    Ok(TokenTree::Literal(Literal::string(&text)).into())
+}
+
+pub fn expand_cstr(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let text = format_number(token_stream)?;
+    let c_string = CString::new(text).map_err(|_| NumTokenParseError::InteriorNul)?;
+    Ok(TokenTree::Literal(Literal::c_string(&c_string)).into())
+}
+
+fn format_number(token_stream: TokenStream) -> Result<String, NumTokenParseError> {
+    // The radix of the digits is chosen by the same options as the radix of
+    // the literal of `num!`
+    let number = evaluate(token_stream)?;
+    if let Some(int_type) = number.options.int_type {
+        if !int_type.contains(number.parsed.value) {
+            return Err(NumTokenParseError::OutOfRange);
+        }
+    }
+
+    Ok(match number.options.radix {
+        Radix::Decimal => number.parsed.to_string(),
+        _ if number.parsed.fraction.is_some() => return Err(NumTokenParseError::FractionalInteger),
+        radix => {
+            let sign = if number.parsed.value < 0 { "-" } else { "" };
+            let value = number.parsed.value.unsigned_abs();
+            match radix {
+                Radix::Hexadecimal => format!("{sign}{value:x}"),
+                Radix::Octal => format!("{sign}{value:o}"),
+                Radix::Decimal | Radix::Binary => format!("{sign}{value:b}"),
+            }
+        }
+    })
+}
@@ -0,0 +1,67 @@
+use proc_macro::{Literal, Spacing, TokenStream, TokenTree};
+
+use word_nums_core::IntType;
+
+use crate::{int_type::Radix, NumTokenParseError, Number};
+
+/// Removes a leading `suffix = "f32":` option from the tokens
+pub fn take_suffix(
+    token_stream: TokenStream,
+) -> Result<(Option<String>, TokenStream), NumTokenParseError> {
+    let mut tokens: Vec<TokenTree> = token_stream.into_iter().collect();
+    let [TokenTree::Ident(ident), TokenTree::Punct(eq), ..] = &tokens[..] else {
+        return Ok((None, tokens.into_iter().collect()));
+    };
+    if ident.to_string() != "suffix" || eq.as_char() != '=' {
+        return Ok((None, tokens.into_iter().collect()));
+    }
+
+    let suffix = match &tokens[2..] {
+        [TokenTree::Literal(literal), TokenTree::Punct(colon), ..]
+            if colon.as_char() == ':' && colon.spacing() == Spacing::Alone =>
+        {
+            literal
+                .to_string()
+                .strip_prefix('"')
+                .and_then(|x| x.strip_suffix('"'))
+                .filter(|x| is_valid_suffix(x))
+                .ok_or_else(|| NumTokenParseError::InvalidSuffix(literal.span()))?
+                .to_string()
+        }
+        _ => return Err(NumTokenParseError::InvalidSuffix(ident.span())),
+    };
+
+    tokens.drain(..4);
+    Ok((Some(suffix), tokens.into_iter().collect()))
+}
+
+/// Creates a literal of the decimal value with the given suffix
+pub fn make_suffixed_literal(number: Number, suffix: &str) -> Result<Literal, NumTokenParseError> {
+    if number.options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // A suffix has to be written after decimal digits, since a hexadecimal
+    // literal would read the "f" of "f32" as another digit
+    if number.options.radix != Radix::Decimal {
+        return Err(NumTokenParseError::RadixWithSuffix);
+    }
+    let is_int_suffix = IntType::from_name(suffix.trim_start_matches('_')).is_some();
+    if is_int_suffix && number.parsed.fraction.is_some() {
+        return Err(NumTokenParseError::FractionalInteger);
+    }
+
+    Ok(format!("{}{suffix}", number.parsed)
+        .parse()
+        .expect("Failed to output suffixed literal"))
+}
+
+/// Whether the suffix looks like an identifier. An `e` can not start a suffix,
+/// since it would be read as an exponent instead.
+fn is_valid_suffix(suffix: &str) -> bool {
+    let mut chars = suffix.chars();
+    chars
+        .next()
+        .is_some_and(|x| (x.is_ascii_alphabetic() || x == '_') && x != 'e' && x != 'E')
+        && chars.all(|x| x.is_ascii_alphanumeric() || x == '_')
+}
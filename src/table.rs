@@ -0,0 +1,70 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::{IntType, Sign};
+
+use crate::{grid, int_type, parse_options, NumTokenParseError};
+
+fn is_arrow(tokens: &[TokenTree]) -> bool {
+    matches!(tokens, [TokenTree::Punct(first), TokenTree::Punct(second)]
+        if first.as_char() == '=' && first.spacing() == Spacing::Joint && second.as_char() == '>')
+}
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+
+    // Each entry is a key in words and an arbitrary value, separated by "=>"
+    let mut entries = Vec::new();
+    for mut entry in grid::split_punct(tokens, ',') {
+        let span = entry.first().map_or_else(Span::call_site, TokenTree::span);
+        let Some(position) = entry.windows(2).position(is_arrow) else {
+            return Err(NumTokenParseError::InvalidTableEntry(span));
+        };
+        let value: TokenStream = entry.split_off(position + 2).into_iter().collect();
+        if value.is_empty() {
+            return Err(NumTokenParseError::InvalidTableEntry(span));
+        }
+        entry.truncate(position);
+
+        let (sign, key) = grid::evaluate_cell(entry, options)?;
+        entries.push((span, sign, key, value));
+    }
+
+    // The entries keep their order, so every key is compared to the ones
+    // before it
+    for (index, (span, _, key, _)) in entries.iter().enumerate() {
+        if entries[..index].iter().any(|(_, _, other, _)| other == key) {
+            return Err(NumTokenParseError::DuplicateKey(*span));
+        }
+    }
+
+    // Just like with num_set!, the keys are only unsigned if all of them are
+    // explicitly positive
+    let int_type = match options.int_type {
+        Some(int_type) => int_type,
+        None => IntType::smallest_fit(
+            entries
+                .iter()
+                .all(|(_, sign, _, _)| matches!(sign, Sign::Positive)),
+            entries.iter().map(|(_, _, key, _)| *key),
+        )
+        .ok_or(NumTokenParseError::OutOfRange)?,
+    };
+
+    let mut elements_stream = TokenStream::new();
+    for (_, _, key, value) in entries {
+        let literal = int_type::radix_literal(int_type, key, options.radix)
+            .ok_or(NumTokenParseError::OutOfRange)?;
+        let mut pair = TokenStream::new();
+        pair.extend([
+            TokenTree::Literal(literal),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+        pair.extend(value);
+        elements_stream.extend([
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, pair)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Bracket, elements_stream)).into())
+}
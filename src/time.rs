@@ -0,0 +1,89 @@
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use word_nums_core::IntType;
+
+use crate::{grid, int_type, parse_options, NumTokenParseError, NumType, Options};
+
+const HOUR_ERROR: &str = "The hour must be between zero and twenty three";
+const MINUTE_ERROR: &str = "The minutes must be between zero and fifty nine";
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let (options, tokens) = parse_options(token_stream)?;
+    if options.int_type.is_some() {
+        return Err(NumTokenParseError::UnexpectedIntType);
+    }
+
+    // The hour and the minutes are separated by a semicolon, just like the
+    // components of a date
+    let components = grid::split_punct(tokens, ';');
+    let time: [NumType; 2] = match &components[..] {
+        [time] => parse_phrase(time.clone(), options)?.into(),
+        [hour, minute] => [
+            parse_hour(hour.clone(), options)?,
+            parse_field(minute.clone(), options, 59, MINUTE_ERROR)?,
+        ],
+        _ => {
+            let span = components
+                .get(2)
+                .and_then(|x| x.first())
+                .map_or_else(Span::call_site, TokenTree::span);
+            return Err(NumTokenParseError::InvalidTime(span));
+        }
+    };
+
+    let mut elements_stream = TokenStream::new();
+    for value in time {
+        let literal =
+            int_type::int_literal(IntType::U8, value).ok_or(NumTokenParseError::OutOfRange)?;
+        elements_stream.extend([
+            TokenTree::Literal(literal),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    Ok(TokenTree::Group(Group::new(Delimiter::Parenthesis, elements_stream)).into())
+}
+
+/// Reads an hour that may be preceded by "half past", "quarter past" or
+/// "quarter to", returning the hour and the minutes
+fn parse_phrase(
+    tokens: Vec<TokenTree>,
+    options: Options,
+) -> Result<(NumType, NumType), NumTokenParseError> {
+    let (minute, offset) = match &tokens[..] {
+        [first, second, ..] if is_word(first, "half") && is_word(second, "past") => (30, 0),
+        [first, second, ..] if is_word(first, "quarter") && is_word(second, "past") => (15, 0),
+        [first, second, ..] if is_word(first, "quarter") && is_word(second, "to") => (45, -1),
+        _ => return Ok((parse_hour(tokens, options)?, 0)),
+    };
+
+    // A quarter to midnight is still on the day before
+    let hour = parse_hour(tokens[2..].to_vec(), options)?;
+    Ok(((hour + offset).rem_euclid(24), minute))
+}
+
+fn parse_hour(tokens: Vec<TokenTree>, options: Options) -> Result<NumType, NumTokenParseError> {
+    match &tokens[..] {
+        [token] if is_word(token, "noon") => Ok(12),
+        [token] if is_word(token, "midnight") => Ok(0),
+        _ => parse_field(tokens, options, 23, HOUR_ERROR),
+    }
+}
+
+fn parse_field(
+    tokens: Vec<TokenTree>,
+    options: Options,
+    max: NumType,
+    message: &'static str,
+) -> Result<NumType, NumTokenParseError> {
+    let span = tokens.first().map_or_else(Span::call_site, TokenTree::span);
+    let (_, value) = grid::evaluate_cell(tokens, options)?;
+    if !(0..=max).contains(&value) {
+        return Err(NumTokenParseError::TimeOutOfRange(span, message));
+    }
+    Ok(value)
+}
+
+fn is_word(token: &TokenTree, word: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string().eq_ignore_ascii_case(word))
+}
@@ -0,0 +1,39 @@
+use proc_macro::{Delimiter, Group, Spacing, TokenStream, TokenTree};
+
+use crate::{evaluate, make_literal, NumTokenParseError};
+
+pub fn expand(token_stream: TokenStream) -> Result<TokenStream, NumTokenParseError> {
+    let mut tokens = token_stream.into_iter();
+
+    // The path ends at the first single colon. The double colons of a path
+    // like `units::Meters` are a joint colon followed by an alone one.
+    let mut path = Vec::new();
+    let mut joint = false;
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ':' && !joint => {
+                if punct.spacing() == Spacing::Alone {
+                    break;
+                }
+                joint = true;
+                path.push(TokenTree::Punct(punct));
+            }
+            Some(token) => {
+                joint = false;
+                path.push(token);
+            }
+            None => return Err(NumTokenParseError::MissingPath),
+        }
+    }
+    if path.is_empty() {
+        return Err(NumTokenParseError::MissingPath);
+    }
+
+    let literal = evaluate(tokens.collect()).and_then(make_literal)?;
+    let mut out: TokenStream = path.into_iter().collect();
+    out.extend([TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenTree::Literal(literal).into(),
+    ))]);
+    Ok(out)
+}
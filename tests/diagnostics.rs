@@ -0,0 +1,4 @@
+#[test]
+fn test_diagnostics() {
+    trybuild::TestCases::new().compile_fail("tests/ui-diagnostics/*.rs");
+}
@@ -0,0 +1,19 @@
+#![feature(f16)]
+
+use word_nums::num_f16;
+
+#[test]
+fn test_num_f16() {
+    assert_eq!(num_f16!(forty two), 42.0f16);
+    assert_eq!(num_f16!(minus zero), -0.0f16);
+    assert_eq!(num_f16!(three point one two five), 3.125f16);
+    assert_eq!(num_f16!(a quarter), 0.25f16);
+    assert_eq!(num_f16!(two thousand forty seven), 2047.0f16);
+    assert_eq!(num_f16!(four thousand ninety six), 4096.0f16);
+    assert_eq!(num_f16!(sixty five thousand five hundred four), f16::MAX);
+}
+
+#[test]
+fn test_num_f16_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui-f16/*.rs");
+}
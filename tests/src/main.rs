@@ -2,7 +2,14 @@ const fn main() {}
 
 #[cfg(test)]
 mod tests {
-    use word_nums::num;
+    use word_nums::{
+        num, num_bigdecimal, num_bits, num_bitwidth, num_cents, num_char_array, num_complex,
+        num_cstr, num_date, num_dotted, num_duration, num_eq, num_grid, num_i16_saturating,
+        num_i8_wrapping, num_in_range, num_len, num_max_for, num_min_for, num_nonzero,
+        num_nonzero_unchecked, num_octal_permissions, num_percent, num_pow, num_range_ex,
+        num_ratio_reduced, num_set, num_str, num_table, num_time, num_u128_wrapping,
+        num_u8_saturating, num_u8_wrapping, num_wrap, number_words_enum, word_consts,
+    };
 
     #[test]
     fn test_single_digits() {
@@ -67,6 +74,32 @@ mod tests {
     #[test]
     fn test_thousands_as_hundreds() {
         assert_eq!(num!(thirteen hundred thirty seven), 1337i16);
+        assert_eq!(num!(nineteen hundred), 1900i16);
+        assert_eq!(num!(nineteen hundred and five), 1905i16);
+        assert_eq!(num!(eighteen hundred twelve), 1812i16);
+        assert_eq!(num!(eleven hundred thousand), 1_100_000i32);
+        assert_eq!(num!(twenty five hundred), 2500i16);
+    }
+
+    #[test]
+    fn test_thousand_groups() {
+        assert_eq!(
+            num!(one hundred twenty three thousand four hundred fifty six),
+            123_456i32
+        );
+        assert_eq!(
+            num!(six hundred fifty four thousand three hundred twenty one),
+            654_321i32
+        );
+        assert_eq!(
+            num!(nine hundred ninety nine thousand nine hundred ninety nine),
+            999_999i32
+        );
+        assert_eq!(
+            num!(one hundred two million three hundred four thousand five hundred six),
+            102_304_506i32
+        );
+        assert_eq!(num!(one hundred thousand one hundred), 100_100i32);
     }
 
     #[test]
@@ -106,4 +139,2216 @@ mod tests {
         assert_eq!(num!(plus zero), 0u8);
         assert_eq!(num!(minus zero), 0i8);
     }
+
+    #[test]
+    fn test_num_len() {
+        const LEN: usize = num_len!(forty two);
+        assert_eq!(LEN, 42usize);
+
+        let arr = [0u8; num_len!(four)];
+        assert_eq!(arr.len(), 4);
+        assert_eq!(num_len!(plus three hundred), 300usize);
+        assert_eq!(num_len!(zero), 0usize);
+    }
+
+    #[test]
+    fn test_const_generic_arguments() {
+        struct Buffer<const N: usize>;
+
+        impl<const N: usize> Buffer<N> {
+            const LEN: usize = N;
+        }
+
+        struct Offset<const N: i16>;
+
+        impl<const N: i16> Offset<N> {
+            const VALUE: i16 = N;
+        }
+
+        assert_eq!(Buffer::<{ num_len!(four) }>::LEN, 4);
+        assert_eq!(Buffer::<{ num!(four) as usize }>::LEN, 4);
+        assert_eq!(Buffer::<{ num!(usize: one thousand) }>::LEN, 1000);
+        assert_eq!(Offset::<{ num!(i16: minus four hundred) }>::VALUE, -400);
+        assert_eq!(Offset::<{ num!(minus four hundred) }>::VALUE, -400);
+    }
+
+    #[test]
+    fn test_loose_trailing_unit() {
+        assert_eq!(num!(loose: five dollars), 5i8);
+        assert_eq!(num!(loose: minus forty two degrees), -42i8);
+        assert_eq!(num!(loose: three hundred), 300i16);
+    }
+
+    #[test]
+    fn test_loose_unicode_digits() {
+        use word_nums_core::{parse_with, Options};
+
+        assert_eq!(num!(loose: "４２"), 42i8);
+        assert_eq!(num!(loose: "4️⃣2️⃣"), 42i8);
+        assert_eq!(num!(loose: minus "４２"), -42i8);
+
+        let mut options = Options::default();
+        assert!(parse_with("４２", options).is_err());
+        options.loose = true;
+        assert_eq!(parse_with("４２", options).map(|x| x.value), Ok(42));
+        assert_eq!(parse_with("7️⃣", options).map(|x| x.value), Ok(7));
+        assert!(parse_with("４x２", options).is_err());
+    }
+
+    #[test]
+    fn test_loose_mixed_literals() {
+        use word_nums_core::{parse_with, Options, ParseError};
+
+        assert_eq!(num!(loose: 2 hundred), 200i16);
+        assert_eq!(num!(loose: forty 2), 42i8);
+        assert_eq!(num!(loose: 3 thousand 4 hundred 2), 3402i16);
+        assert_eq!(num!(loose: minus 5 million), -5_000_000i32);
+        assert_eq!(num!(loose: 5 dollars), 5i8);
+        assert_eq!(num!(loose: "１００ dollars"), 100i8);
+
+        let mut options = Options::default();
+        assert_eq!(
+            parse_with("2 hundred", options),
+            Err(ParseError::MixedLiteral { index: 0 })
+        );
+        options.loose = true;
+        assert_eq!(parse_with("2 hundred", options).map(|x| x.value), Ok(200));
+        assert_eq!(parse_with("forty 0x2", options).map(|x| x.value), Ok(42));
+        assert_eq!(
+            parse_with("forty 2.5", options),
+            Err(ParseError::MixedLiteral { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_polish() {
+        assert_eq!(num!(pl: dwadzieścia jeden), 21i8);
+        assert_eq!(num!(pl: sto), 100i8);
+        assert_eq!(num!(pl: pięć tysięcy), 5000i16);
+        assert_eq!(num!(pl: dwa tysiące trzysta czterdzieści pięć), 2345i16);
+        assert_eq!(num!(pl: tysiąc), 1000i16);
+        assert_eq!(num!(pl: minus dziewięćset), -900i16);
+    }
+
+    #[test]
+    fn test_implicit_one_languages() {
+        use word_nums_core::{parse, parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(minus thousand), -1000i16);
+        assert_eq!(parse("million"), Ok(1_000_000));
+        assert_eq!(num!(pl: tysiąc sto), 1100i16);
+        assert_eq!(num!(el: chilia), 1000i16);
+        assert_eq!(num!(he: elef), 1000i16);
+
+        // The plural forms need a count before them
+        let parse_in = |language, input| {
+            let mut options = Options::default();
+            options.language = language;
+            parse_with(input, options).and_then(word_nums_core::Number::integer)
+        };
+        assert_eq!(
+            parse_in(Language::Polish, "tysięcy"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(
+            parse_in(Language::Polish, "minus Miliony"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse_in(Language::Greek, "chiliades"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(
+            parse_in(Language::Hebrew, "alafim"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(parse_in(Language::Polish, "pięć tysięcy"), Ok(5000));
+        assert_eq!(parse_in(Language::Hebrew, "shloshet alafim"), Ok(3000));
+    }
+
+    #[test]
+    fn test_explicit_type() {
+        assert_eq!(num!(u64: forty two), 42u64);
+        assert_eq!(num!(i16: minus one), -1i16);
+        assert_eq!(num!(u8: plus two hundred fifty five), 255u8);
+        assert_eq!(num!(i128: seven), 7i128);
+        assert_eq!(num!(usize: one thousand), 1000usize);
+    }
+
+    #[test]
+    fn test_explicit_type_zero() {
+        assert_eq!(num!(u8: zero), 0u8);
+        assert_eq!(num!(i8: zero), 0i8);
+        assert_eq!(num!(u8: plus zero), 0u8);
+        assert_eq!(num!(i8: plus zero), 0i8);
+        assert_eq!(num!(u8: minus zero), 0u8);
+        assert_eq!(num!(i8: minus zero), 0i8);
+        assert_eq!(num!(u128: minus zero), 0u128);
+        assert_eq!(num!(isize: zero), 0isize);
+        assert_eq!(num!(usize: minus zero), 0usize);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(num!(Forty TWO), 42i8);
+        assert_eq!(num!(MINUS One Hundred), -100i8);
+        assert_eq!(num!(pl: PIĘĆ Tysięcy), 5000i16);
+    }
+
+    macro_rules! repeated_zeros {
+        ([$($words:tt)*] x $($rest:tt)*) => {
+            repeated_zeros!([$($words)* $($words)*] $($rest)*)
+        };
+        ([$($words:tt)*]) => {
+            num!($($words)* forty two)
+        };
+    }
+
+    #[test]
+    fn test_large_input() {
+        // 2^14 repetitions of "zero" followed by "forty two". This only checks
+        // that large inputs still give the right value, their parsing speed
+        // is measured by the parse benchmark of word-nums-core.
+        assert_eq!(repeated_zeros!([zero] x x x x x x x x x x x x x x), 42i8);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_fractions() {
+        assert_eq!(num!(three and a half), 3.5f64);
+        assert_eq!(num!(two and a quarter), 2.25f64);
+        assert_eq!(num!(one and three quarters), 1.75f64);
+        assert_eq!(num!(a half), 0.5f64);
+        assert_eq!(num!(minus ten and a half), -10.5f64);
+        assert_eq!(num!(minus a quarter), -0.25f64);
+        assert_eq!(num!(two thousand and a half), 2000.5f64);
+        assert_eq!(num!(a dozen and a half), 18i8);
+        assert_eq!(num!(two dozen and a half), 30i8);
+        assert_eq!(num!(two dozen and a quarter), 27i8);
+        assert_eq!(num!(minus a dozen and three quarters), -21i8);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_named_fractions() {
+        use word_nums_core::{parse_with, Fraction, Options, ParseError};
+
+        assert_eq!(num!(fractions: three whole five tenths), 3.5f64);
+        assert_eq!(num!(fractions: two whole twenty five hundredths), 2.25f64);
+        assert_eq!(num!(fractions: minus one whole one tenth), -1.1f64);
+        assert_eq!(num!(fractions: seventy five hundredths), 0.75f64);
+        assert_eq!(num!(fractions: twelve tenths), 1.2f64);
+        assert_eq!(num!(fractions: zero whole five thousandths), 0.005f64);
+        assert_eq!(
+            num!(fractions: three whole five hundred millionths),
+            3.0005f64
+        );
+        assert_eq!(num!(fractions: Three Whole Five Tenths), 3.5f64);
+        assert_eq!(num!(fractions: three and a half), 3.5f64);
+        assert_eq!(num!(fractions: forty two), 42i8);
+
+        let mut options = Options::default();
+        assert_eq!(
+            parse_with("three whole five tenths", options),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        options.fractions = true;
+        assert_eq!(
+            parse_with("three whole zero tenths", options).map(|x| x.fraction),
+            Ok(Some(Fraction {
+                numerator: 0,
+                denominator: 10
+            }))
+        );
+        assert_eq!(
+            parse_with("three whole twelve tenths", options),
+            Err(ParseError::UnexpectedFraction)
+        );
+        assert_eq!(
+            parse_with("three whole minus five tenths", options),
+            Err(ParseError::UnexpectedSign { index: 2 })
+        );
+        assert_eq!(
+            parse_with("three whole five fives tenths", options),
+            Err(ParseError::InvalidWord { index: 3 })
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_decimal_point() {
+        use word_nums_core::{parse_with, Options, ParseError};
+
+        assert_eq!(num!(three point one two five), 3.125f64);
+        assert_eq!(num!(minus three point five), -3.5f64);
+        assert_eq!(num!(minus two and a half), -2.5f64);
+        assert_eq!(num!(minus zero point two five), -0.25f64);
+        assert!(num!(minus zero point zero).is_sign_negative());
+        assert!(num!(negative zero and a half).is_sign_negative());
+        assert!(num!(zero point zero).is_sign_positive());
+        assert_eq!(num!(minus point five), -0.5f64);
+        assert_eq!(num!(one hundred twenty point zero seven), 120.07f64);
+
+        let parse = |input| parse_with(input, Options::default());
+        assert_eq!(parse("three point"), Err(ParseError::InvalidDecimal));
+        assert_eq!(parse("three point twelve"), Err(ParseError::InvalidDecimal));
+        assert_eq!(
+            parse("three point five hundred"),
+            Err(ParseError::InvalidDecimal)
+        );
+        assert_eq!(
+            parse("three point one point four"),
+            Err(ParseError::MultiplePoints { index: 3 })
+        );
+        assert_eq!(
+            parse("point point"),
+            Err(ParseError::MultiplePoints { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_integer_and() {
+        assert_eq!(num!(one hundred and five), 105i8);
+    }
+
+    #[test]
+    fn test_num_grid() {
+        assert_eq!(num_grid!(u8: one, two; three, four), [[1u8, 2], [3, 4]]);
+        assert_eq!(num_grid!(one, two, three), [[1i8, 2, 3]]);
+        assert_eq!(
+            num_grid!(minus one, zero; three hundred, four;),
+            [[-1i16, 0], [300, 4]]
+        );
+        assert_eq!(num_grid!(plus two hundred; plus ten), [[200u8], [10]]);
+        assert_eq!(num_grid!(plus two hundred; ten), [[200i16], [10]]);
+    }
+
+    #[test]
+    fn test_approx() {
+        assert_eq!(num!(approx: a couple), 2i8);
+        assert_eq!(num!(approx: a few), 3i8);
+        assert_eq!(num!(approx: several), 7i8);
+        assert_eq!(num!(approx: a couple of hundred), 200i16);
+        assert_eq!(num!(approx: half of a thousand), 500i16);
+        assert_eq!(num!(approx: minus half of ten), -5i8);
+        assert_eq!(num!(approx: a couple of thousand), 2000i16);
+    }
+
+    #[test]
+    fn test_approx_multipliers() {
+        assert_eq!(num!(approx: several hundred thousand), 700_000i32);
+        assert_eq!(num!(approx: a few thousand), 3000i16);
+        assert_eq!(num!(approx: a few hundred thousand), 300_000i32);
+        assert_eq!(num!(approx: several thousand five hundred), 7500i16);
+        assert_eq!(num!(approx: a couple million), 2_000_000i32);
+        assert_eq!(
+            num!(loose: approx: about several hundred thousand people),
+            700_000i32
+        );
+        assert_eq!(num!(loose: approx: a few thousand dollars), 3000i16);
+    }
+
+    #[test]
+    fn test_approx_collectives() {
+        assert_eq!(num!(approx: couple dozen), 24i8);
+        assert_eq!(num!(approx: a couple dozen), 24i8);
+        assert_eq!(num!(approx: a couple of dozen), 24i8);
+        assert_eq!(num!(approx: few hundred), 300i16);
+        assert_eq!(num!(approx: several thousand), 7000i16);
+        assert_eq!(num!(approx: a few score), 60i8);
+        assert_eq!(num!(approx: several dozen hundred), 8400i16);
+        assert_eq!(num!(approx: minus a couple gross), -288i16);
+    }
+
+    #[test]
+    fn test_literal_passthrough() {
+        assert_eq!(num!(42), num!(forty two));
+        assert_eq!(num!(42), 42i8);
+        assert_eq!(num!(1_000), 1000i16);
+        assert_eq!(num!(minus 300), -300i16);
+        assert_eq!(num!(plus 0xff), 255u8);
+        assert_eq!(num!(u32: 0b101), 5u32);
+        assert_eq!(num_len!(8), 8usize);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_float_literal_passthrough() {
+        assert_eq!(num!(3.25), 3.25f64);
+        assert_eq!(num!(minus 0.5), -0.5f64);
+        assert_eq!(num!(2.05), 2.05f64);
+    }
+
+    #[test]
+    fn test_korean() {
+        assert_eq!(num!(ko: man), 10_000i16);
+        assert_eq!(num!(ko: il man), 10_000i16);
+        assert_eq!(num!(ko: eok), 100_000_000i32);
+        assert_eq!(num!(ko: sam sip i), 32i8);
+        assert_eq!(num!(ko: i-cheon-o-baek-sip), 2510i16);
+        assert_eq!(num!(ko: sam baek i sip man), 3_200_000i32);
+
+        // The place of ten thousands is skipped entirely
+        assert_eq!(num!(ko: il eok sam man sa), 100_030_004i32);
+        assert_eq!(num!(ko: o eok chil), 500_000_007i32);
+    }
+
+    #[test]
+    fn test_core_parse() {
+        use word_nums_core::{parse, parse_with, Language, Options, ParseError};
+
+        assert_eq!(parse("forty-two"), Ok(42));
+        assert_eq!(parse("Minus Three Hundred"), Ok(-300));
+        assert_eq!(parse("1_000"), Ok(1000));
+        assert_eq!(parse("two and a half"), Err(ParseError::NotWhole));
+        assert_eq!(
+            parse("forty gazillion"),
+            Err(ParseError::FictionalWord {
+                index: 1,
+                word: "gazillion"
+            })
+        );
+        assert_eq!(
+            parse("forty bananas"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse("one minus"),
+            Err(ParseError::UnexpectedSign { index: 1 })
+        );
+        assert_eq!(parse("forty 2"), Err(ParseError::MixedLiteral { index: 1 }));
+        assert_eq!(parse("a quadrillion octillion"), Err(ParseError::Overflow));
+
+        let number = parse_with("minus two and a half", Options::default());
+        assert_eq!(number.map(|x| x.to_string()), Ok("-2.5".to_string()));
+
+        let mut options = Options::default();
+        options.language = Language::Korean;
+        assert_eq!(
+            parse_with("sam baek i sip man", options).and_then(word_nums_core::Number::integer),
+            Ok(3_200_000)
+        );
+    }
+
+    /// Inputs that overflow the internal representation or are otherwise
+    /// unusual, which the parser has to reject without panicking
+    const REGRESSION_SEEDS: &[&str] = &[
+        "",
+        "-",
+        "and",
+        "minus",
+        "a half",
+        "half of half of",
+        "quarter quarters",
+        "170141183460469231731687303715884105728",
+        "99999999999999999999999999999999999999999.5",
+        "0.000000000000000000000000000000000000000001",
+        "a quadrillion octillion",
+        "ninety nine quintillion octillion",
+        "minus a million billion trillion quadrillion",
+        "octillion octillion octillion",
+    ];
+
+    #[test]
+    fn test_core_regression_seeds() {
+        for seed in REGRESSION_SEEDS {
+            let _ = word_nums_core::parse(seed);
+        }
+
+        // Input without a number word is not zero
+        for seed in ["", "-", "and", "minus", "plus and"] {
+            assert_eq!(
+                word_nums_core::parse(seed),
+                Err(word_nums_core::ParseError::Empty)
+            );
+        }
+    }
+
+    #[test]
+    fn test_core_to_words_scales() {
+        use word_nums_core::{to_words, to_words_with, Scale};
+
+        assert_eq!(to_words_with(1_000_000_000, Scale::Short), "one billion");
+        assert_eq!(
+            to_words_with(1_000_000_000, Scale::Long),
+            "one thousand million"
+        );
+        assert_eq!(to_words_with(10_i128.pow(12), Scale::Long), "one billion");
+        assert_eq!(
+            to_words_with(2_500_000_000, Scale::Long),
+            "two thousand five hundred million"
+        );
+        assert_eq!(
+            to_words_with(-1_234_567_890, Scale::Long),
+            "minus one thousand two hundred thirty four million five hundred sixty seven \
+             thousand eight hundred ninety"
+        );
+        assert_eq!(to_words_with(10_i128.pow(18), Scale::Long), "one trillion");
+        assert_eq!(
+            to_words_with(i128::MAX, Scale::Long).split(' ').next(),
+            Some("one")
+        );
+        assert_eq!(to_words_with(999_999, Scale::Long), to_words(999_999));
+        assert_eq!(to_words_with(42, Scale::default()), to_words(42));
+    }
+
+    #[test]
+    fn test_core_to_rust_literal() {
+        use word_nums_core::to_rust_literal;
+
+        fn literal_of<T: std::fmt::Display>(value: T) -> String {
+            let name = std::any::type_name::<T>();
+            format!("{value}{name}")
+        }
+
+        assert_eq!(to_rust_literal(0), literal_of(num!(zero)));
+        assert_eq!(to_rust_literal(42), literal_of(num!(forty two)));
+        assert_eq!(
+            to_rust_literal(127),
+            literal_of(num!(one hundred twenty seven))
+        );
+        assert_eq!(
+            to_rust_literal(128),
+            literal_of(num!(one hundred twenty eight))
+        );
+        assert_eq!(
+            to_rust_literal(-128),
+            literal_of(num!(minus one hundred twenty eight))
+        );
+        assert_eq!(
+            to_rust_literal(-129),
+            literal_of(num!(minus one hundred twenty nine))
+        );
+        assert_eq!(to_rust_literal(70_000), literal_of(num!(seventy thousand)));
+        assert_eq!(
+            to_rust_literal(5_000_000_000),
+            literal_of(num!(five billion))
+        );
+        assert_eq!(
+            to_rust_literal(10_i128.pow(20)),
+            literal_of(num!(one hundred quintillion))
+        );
+        assert_eq!(to_rust_literal(42), "42i8");
+        assert_eq!(to_rust_literal(i128::MIN), format!("{}i128", i128::MIN));
+    }
+
+    #[test]
+    fn test_core_to_words() {
+        use word_nums_core::{parse, to_words, ParseError};
+
+        assert_eq!(to_words(0), "zero");
+        assert_eq!(to_words(7), "seven");
+        assert_eq!(to_words(-42), "minus forty two");
+        assert_eq!(to_words(110), "one hundred ten");
+        assert_eq!(to_words(1_000_001), "one million one");
+        assert_eq!(to_words(-0), "zero");
+        assert_eq!(
+            to_words(999_999),
+            "nine hundred ninety nine thousand nine hundred ninety nine"
+        );
+        assert_eq!(
+            to_words(i64::MIN.into()),
+            "minus nine quintillion two hundred twenty three quadrillion three hundred \
+             seventy two trillion thirty six billion eight hundred fifty four million \
+             seven hundred seventy five thousand eight hundred eight"
+        );
+
+        // Only the magnitude of the smallest i128 is too large to read back
+        assert_eq!(parse(&to_words(i128::MAX)), Ok(i128::MAX));
+        assert_eq!(parse(&to_words(i128::MIN + 1)), Ok(i128::MIN + 1));
+        assert_eq!(parse(&to_words(i128::MIN)), Err(ParseError::Overflow));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_core_to_words_round_trip(value: i64) {
+            let value = i128::from(value);
+            let words = word_nums_core::to_words(value);
+            proptest::prop_assert_eq!(word_nums_core::parse(&words), Ok(value));
+        }
+
+        #[test]
+        fn test_core_to_words_round_trip_small(value in -100_000i128..100_000) {
+            let words = word_nums_core::to_words(value);
+            proptest::prop_assert_eq!(word_nums_core::parse(&words), Ok(value));
+        }
+
+        #[test]
+        fn test_core_never_panics_on_ascii(input in "[ -~]{0,64}") {
+            let _ = word_nums_core::parse(&input);
+        }
+
+        #[test]
+        fn test_core_never_panics_on_words(
+            words in proptest::collection::vec(
+                proptest::sample::select(&[
+                    "zero", "one", "a", "nine", "nineteen", "ninety", "hundred", "thousand",
+                    "million", "quintillion", "octillion", "and", "minus", "plus", "half",
+                    "quarters", "of", "170141183460469231731687303715884105727", "9.99",
+                ][..]),
+                0..16,
+            )
+        ) {
+            let mut options = word_nums_core::Options::default();
+            options.approx = true;
+            let _ = word_nums_core::parse_with(&words.join(" "), options);
+            options.digits = true;
+            let _ = word_nums_core::parse_with(&words.join(" "), options);
+            options.digits = false;
+            options.folksy = true;
+            let _ = word_nums_core::parse_with(&words.join(" "), options);
+        }
+    }
+
+    #[test]
+    fn test_dozen() {
+        assert_eq!(num!(a dozen), 12i8);
+        assert_eq!(num!(dozen), 12i8);
+        assert_eq!(num!(two dozen), 24i8);
+        assert_eq!(num!(loose: two dozen eggs), 24i8);
+        assert_eq!(num!(a dozen hundred), 1200i16);
+        assert_eq!(num!(five hundred and a dozen), 512i16);
+    }
+
+    mod units {
+        #[derive(Debug, PartialEq, Eq)]
+        pub struct Meters<T>(pub T);
+    }
+
+    #[test]
+    fn test_num_wrap() {
+        use units::Meters;
+
+        assert_eq!(num_wrap!(Meters: forty two), Meters(42i8));
+        assert_eq!(num_wrap!(Meters: plus three hundred), Meters(300u16));
+        assert_eq!(num_wrap!(units::Meters: u64: seven), Meters(7u64));
+        assert_eq!(num_wrap!(Meters::<i8>: minus one), Meters(-1i8));
+        assert_eq!(num_wrap!(Some: loose: five dollars), Some(5i8));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(num!(two thousand and five), 2005i16);
+        assert_eq!(num!(two thousand five), 2005i16);
+
+        assert_eq!(num!(digits: nineteen oh five), 1905i16);
+        assert_eq!(num!(digits: twenty twenty), 2020i16);
+        assert_eq!(num!(digits: nineteen eighty four), 1984i16);
+        assert_eq!(num!(digits: one two three), 123i8);
+        assert_eq!(num!(digits: minus four two), -42i8);
+
+        assert_eq!(num!(digits: zero five), 5i8);
+        assert_eq!(num!(digits: zero zero seven), 7i8);
+        assert_eq!(num!(digits: twelve oh three), 1203i16);
+        assert_eq!(num!(digits: twenty oh five), 2005i16);
+        assert_eq!(num!(digits: oh oh), 0i8);
+    }
+
+    #[test]
+    fn test_digits_errors() {
+        use word_nums_core::{parse, parse_with, Options, ParseError};
+
+        let mut options = Options::default();
+        options.digits = true;
+        assert_eq!(
+            parse_with("nineteen hundred", options),
+            Err(ParseError::UnexpectedMultiplier)
+        );
+        assert_eq!(
+            parse_with("two dozen", options),
+            Err(ParseError::UnexpectedMultiplier)
+        );
+        assert_eq!(
+            parse("nineteen oh five"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+    }
+
+    word_consts! {
+        ANSWER = forty two;
+        pub LIMIT: u32 = one thousand;
+        BYTE = u8: two hundred fifty five;
+        FREEZING = minus forty;
+        UNSIGNED = plus three hundred;
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_word_consts() {
+        assert_eq!(ANSWER, 42i8);
+        assert_eq!(LIMIT, 1000u32);
+        assert_eq!(BYTE, 255u8);
+        assert_eq!(FREEZING, -40i8);
+        assert_eq!(UNSIGNED, 300u16);
+
+        word_consts! {
+            HALF = a half;
+            DOZEN = a dozen
+        }
+        assert_eq!(HALF, 0.5f64);
+        assert_eq!(DOZEN, 12i8);
+        assert_eq!([0u8; LIMIT as usize].len(), 1000);
+    }
+
+    #[test]
+    fn test_dialects() {
+        use word_nums_core::{parse_with, Dialect, Options, ParseError};
+
+        assert_eq!(num!(us: one hundred one), 101i8);
+        assert_eq!(num!(us: one hundred and one), 101i8);
+        assert_eq!(num!(gb: one hundred and one), 101i8);
+        assert_eq!(num!(gb: two thousand and five), 2005i16);
+        assert_eq!(num!(gb: twenty one), 21i8);
+        assert_eq!(num!(gb: one hundred), 100i8);
+
+        let mut options = Options::default();
+        options.dialect = Dialect::British;
+        assert_eq!(
+            parse_with("one hundred one", options),
+            Err(ParseError::MissingAnd)
+        );
+        assert_eq!(
+            parse_with("three thousand two hundred twenty", options),
+            Err(ParseError::MissingAnd)
+        );
+    }
+
+    #[test]
+    fn test_num_bitwidth() {
+        assert_eq!(num_bitwidth!(two hundred fifty five), 8usize);
+        assert_eq!(num_bitwidth!(two hundred fifty six), 9usize);
+        assert_eq!(num_bitwidth!(zero), 0usize);
+        assert_eq!(num_bitwidth!(one), 1usize);
+        assert_eq!(
+            num_bitwidth!(plus sixty five thousand five hundred thirty five),
+            16usize
+        );
+        assert_eq!(num_bitwidth!(minus one), 1usize);
+        assert_eq!(num_bitwidth!(minus one hundred twenty eight), 8usize);
+        assert_eq!(num_bitwidth!(minus one hundred twenty nine), 9usize);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_num_percent() {
+        assert_eq!(num_percent!(fifty percent), 0.5f64);
+        assert_eq!(num_percent!(one hundred percent), 1f64);
+        assert_eq!(num_percent!(three percent), 0.03f64);
+        assert_eq!(num_percent!(minus fifty percent), -0.5f64);
+        assert_eq!(num_percent!(twelve and a half percent), 0.125f64);
+
+        assert_eq!(num_percent!(fifty percent of two hundred), 100i8);
+        assert_eq!(num_percent!(fifty percent of plus two hundred), 100u8);
+        assert_eq!(num_percent!(minus ten percent of a thousand), -100i8);
+        assert_eq!(
+            num_percent!(twelve and a half percent of eight hundred),
+            100i8
+        );
+        assert_eq!(num_percent!(two hundred percent of three and a half), 7i8);
+    }
+
+    #[test]
+    fn test_loose_qualifier() {
+        use word_nums_core::{parse, parse_with, Options, ParseError};
+
+        assert_eq!(num!(loose: approximately forty two), 42i8);
+        assert_eq!(num!(loose: Exactly one hundred), 100i8);
+        assert_eq!(num!(loose: roughly plus three hundred), 300u16);
+
+        let mut options = Options::default();
+        options.loose = true;
+        assert_eq!(
+            parse_with("forty approximately two", options),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse_with("minus exactly two", options),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse("approximately forty two"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_turkish() {
+        assert_eq!(num!(tr: yirmi bir), 21i8);
+        assert_eq!(num!(tr: yüz), 100i8);
+        assert_eq!(num!(tr: yüz yirmi üç), 123i8);
+        assert_eq!(num!(tr: bin), 1000i16);
+        assert_eq!(num!(tr: iki bin yüz), 2100i16);
+        assert_eq!(num!(tr: bin dokuz yüz seksen dört), 1984i16);
+        assert_eq!(num!(tr: minus üç milyon), -3_000_000i32);
+    }
+
+    #[test]
+    fn test_num_str() {
+        const ANSWER: &str = num_str!(forty two);
+        assert_eq!(ANSWER, "42");
+        assert_eq!(num_str!(minus ten), "-10");
+        assert_eq!(num_str!(zero), "0");
+        assert_eq!(num_str!(two and a half), "2.5");
+        assert_eq!(num_str!(hex_lit: two hundred fifty five), "ff");
+        assert_eq!(num_str!(hex_lit: minus two hundred fifty five), "-ff");
+        assert_eq!(num_str!(oct_lit: u8: sixty four), "100");
+        assert_eq!(num_str!(u8: oct_lit: sixty four), "100");
+        assert_eq!(num_str!(bin_lit: five), "101");
+
+        // Reading hexadecimal digits does not change the radix of the output
+        assert_eq!(num_str!(hex: f f), "255");
+        assert_eq!(num_str!(hex: minus one zero), "-16");
+        assert_eq!(num_str!(hex: hex_lit: f f), "ff");
+    }
+
+    #[test]
+    fn test_num_str_trailing_zeros() {
+        assert_eq!(num_str!(three point one zero), "3.10");
+        assert_eq!(num_str!(one point zero), "1.0");
+        assert_eq!(num_str!(two point five zero zero), "2.500");
+        assert_eq!(num_str!(minus zero point zero zero), "-0.00");
+        assert_eq!(num_str!(1.50), "1.50");
+        assert_eq!(num_cstr!(three point one zero), c"3.10");
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_custom_suffix() {
+        assert_eq!(num!(suffix = "f32": forty two), 42f32);
+        assert_eq!(num!(suffix = "u64": three hundred), 300u64);
+        assert_eq!(num!(suffix = "f32": minus two and a half), -2.5f32);
+        assert_eq!(num!(suffix = "_u16": loose: ten dollars), 10u16);
+    }
+
+    #[test]
+    fn test_greek() {
+        assert_eq!(num!(el: eikosi ena), 21i8);
+        assert_eq!(num!(el: ekato), 100i8);
+        assert_eq!(num!(el: chilia), 1000i16);
+        assert_eq!(num!(el: ekaton eikosi tria), 123i8);
+        assert_eq!(num!(el: chilia kai ena), 1001i16);
+        assert_eq!(num!(el: dyo chiliades diakosia dekapente), 2215i16);
+        assert_eq!(num!(el: deka okto), 18i8);
+        assert_eq!(num!(el: triakosies chiliades), 300_000i32);
+    }
+
+    #[test]
+    fn test_num_set() {
+        assert_eq!(num_set!(one; two; three), [1i8, 2, 3]);
+        assert_eq!(num_set!(three hundred; minus one; two), [-1i16, 2, 300]);
+        assert_eq!(num_set!(plus two; plus one), [1u8, 2]);
+        assert_eq!(num_set!(u32: seven;), [7u32]);
+        assert_eq!(num_set!(dedup: one; two; two; three), [1i8, 2, 3]);
+        assert_eq!(num_set!(dedup: five; 5; a dozen; twelve), [5i8, 12]);
+    }
+
+    #[test]
+    fn test_folksy() {
+        use word_nums_core::{parse, ParseError};
+
+        assert_eq!(num!(folksy: thousand thousand), 1_000_000i32);
+        assert_eq!(num!(folksy: hundred hundred), 10_000i16);
+        assert_eq!(num!(folksy: a thousand thousand thousand), 1_000_000_000i32);
+        assert_eq!(num!(folksy: two hundred hundred and five), 20_005i16);
+        assert_eq!(num!(folksy: hundred thousand), 100_000i32);
+
+        assert_eq!(
+            parse("thousand thousand"),
+            Err(ParseError::RepeatedMultiplier)
+        );
+        assert_eq!(parse("hundred thousand"), Ok(100_000));
+    }
+
+    #[test]
+    fn test_folksy_dozens() {
+        use word_nums_core::{parse, parse_with, Options, ParseError};
+
+        assert_eq!(num!(folksy: dozen dozen), 144i16);
+        assert_eq!(num!(folksy: a dozen dozen), 144i16);
+        assert_eq!(num!(folksy: dozen score), 240i16);
+        assert_eq!(num!(folksy: a score dozen), 240i16);
+        assert_eq!(num!(folksy: two dozen dozen), 288i16);
+        assert_eq!(num!(folksy: a gross dozen), 1728i16);
+        assert_eq!(num!(a gross), 144i16);
+        assert_eq!(num!(three score and ten), 70i8);
+        assert_eq!(num!(four scores), 80i8);
+
+        // A ream of paper is 500 sheets, which multiplies like "hundred" does
+        assert_eq!(num!(two reams), 1000i16);
+        assert_eq!(num!(a ream), 500i16);
+        assert_eq!(num!(three reams and twenty), 1520i16);
+        assert_eq!(num!(two hundred reams), 100_000i32);
+        assert_eq!(num!(folksy: a dozen reams), 6000i16);
+        assert_eq!(parse("ream ream"), Err(ParseError::RepeatedMultiplier));
+
+        assert_eq!(parse("dozen dozen"), Err(ParseError::RepeatedMultiplier));
+
+        // Different count nouns in a row need folding as well
+        assert_eq!(parse("a dozen score"), Err(ParseError::RepeatedMultiplier));
+        assert_eq!(parse("a score dozen"), Err(ParseError::RepeatedMultiplier));
+
+        let mut options = Options::default();
+        options.folksy = true;
+        assert_eq!(
+            parse_with("a dozen score", options).map(|x| x.value),
+            Ok(240)
+        );
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        trybuild::TestCases::new().compile_fail("ui/*.rs");
+    }
+
+    #[test]
+    fn test_scale() {
+        use word_nums_core::{parse_with, Options, Scale};
+
+        assert_eq!(num!(short: two billion), 2_000_000_000i32);
+        assert_eq!(num!(long: two billion), 2_000_000_000_000i64);
+        assert_eq!(num!(long: one thousand million), 1_000_000_000i32);
+        assert_eq!(num!(long: one trillion), 10i64.pow(18));
+        assert_eq!(num!(long: a million), 1_000_000i32);
+        assert_eq!(num!(long: five hundred thousand), 500_000i32);
+        assert_eq!(num!(long: ordinal: billionth), 10i64.pow(12));
+        assert_eq!(num!(long: de: eine billion), 10i64.pow(12));
+
+        let mut options = Options::default();
+        options.scale = Scale::Long;
+        assert_eq!(
+            parse_with("one sextillion", options).map(|x| x.value),
+            Ok(10i128.pow(36))
+        );
+        assert!(parse_with("one septillion", options).is_err());
+        assert!(parse_with("one septillion", Options::default()).is_ok());
+    }
+
+    #[test]
+    fn test_arith() {
+        use word_nums_core::{parse, parse_with, Options, ParseError};
+
+        assert_eq!(num!(arith: three times four), 12i8);
+        assert_eq!(num!(arith: twelve x twelve), 144i16);
+        assert_eq!(num!(arith: a thousand multiplied by minus five), -5000i16);
+        assert_eq!(num!(arith: plus sixteen times plus sixteen), 256u16);
+        assert_eq!(num!(arith: forty two), 42i8);
+
+        let mut options = Options::default();
+        options.arith = true;
+        assert_eq!(
+            parse_with("one octillion times one quadrillion", options),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            parse_with("two times three times four", options).map(|x| x.value),
+            Ok(24)
+        );
+        assert_eq!(
+            parse("three times four"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_arith_expressions() {
+        use word_nums_core::{parse_with, Options, ParseError};
+
+        assert_eq!(num!(arith: (two plus three) times four), 20i8);
+        assert_eq!(num!(arith: two plus three times four), 14i8);
+        assert_eq!(num!(arith: four times three plus two), 14i8);
+        assert_eq!(num!(arith: four times (three plus two)), 20i8);
+        assert_eq!(num!(arith: ten minus four minus three), 3i8);
+        assert_eq!(num!(arith: ten minus (four minus three)), 9i8);
+        assert_eq!(num!(arith: two minus minus three), 5i8);
+        assert_eq!(num!(arith: minus (two plus three)), -5i8);
+        assert_eq!(num!(arith: ((one hundred))), 100i8);
+        assert_eq!(
+            num!(arith: (one thousand plus (two times five)) x three),
+            3030i16
+        );
+        assert_eq!(num!(arith: twenty-one plus twenty one), 42i8);
+        assert_eq!(num!(arith: plus two plus plus three), 5u8);
+        assert_eq!(num!(arith: one minus two), -1i8);
+        assert_eq!(
+            num!(i64: arith: (one million) times (one million)),
+            1_000_000_000_000i64
+        );
+
+        let mut options = Options::default();
+        options.arith = true;
+        let parse = |input| parse_with(input, options).map(|x| x.value);
+        assert_eq!(parse("( six minus one ) times two"), Ok(10));
+        assert_eq!(
+            parse("( six minus one times two"),
+            Err(ParseError::UnbalancedParenthesis { index: 0 })
+        );
+        assert_eq!(
+            parse("six minus one ) times two"),
+            Err(ParseError::UnbalancedParenthesis { index: 3 })
+        );
+        assert_eq!(parse("six plus"), Err(ParseError::MissingOperand));
+        assert_eq!(parse("times six"), Err(ParseError::MissingOperand));
+        assert_eq!(parse("( )"), Err(ParseError::MissingOperand));
+        assert_eq!(
+            parse("six plus ( seven apples )"),
+            Err(ParseError::InvalidWord { index: 4 })
+        );
+        assert_eq!(parse("two and a half plus one"), Err(ParseError::NotWhole));
+    }
+
+    #[test]
+    fn test_hebrew() {
+        use word_nums_core::{parse_with, Language, Options};
+
+        assert_eq!(num!(he: esrim ve-echad), 21i8);
+        assert_eq!(num!(he: mea), 100i8);
+        assert_eq!(num!(he: elef), 1000i16);
+        assert_eq!(num!(he: esrim ve echad), 21i8);
+        assert_eq!(num!(he: shlosha asar), 13i8);
+        assert_eq!(num!(he: shalosh meot arbaim ve achat), 341i16);
+        assert_eq!(num!(he: alpayim ve-chamesh), 2005i16);
+        assert_eq!(num!(he: shloshet alafim), 3000i16);
+
+        let mut options = Options::default();
+        options.language = Language::Hebrew;
+        assert_eq!(
+            parse_with("esrim ve'echad", options).and_then(word_nums_core::Number::integer),
+            Ok(21)
+        );
+        assert_eq!(
+            parse_with("arba'a me'ot", options).and_then(word_nums_core::Number::integer),
+            Ok(400)
+        );
+    }
+
+    #[test]
+    fn test_num_cstr() {
+        assert_eq!(num_cstr!(forty two).to_str(), Ok("42"));
+        assert_eq!(num_cstr!(forty two).to_bytes_with_nul(), b"42\0");
+        assert_eq!(num_cstr!(minus one thousand), c"-1000");
+        assert_eq!(num_cstr!(two and a half), c"2.5");
+        assert_eq!(num_cstr!(oct_lit: sixty four), c"100");
+    }
+
+    #[test]
+    fn test_localized_ordinals() {
+        use word_nums_core::{parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(de: ordinal: dritte), 3i8);
+        assert_eq!(num!(de: ordinal: erste), 1i8);
+        assert_eq!(num!(de: ordinal: zweiten), 2i8);
+        assert_eq!(num!(de: ordinal: siebter), 7i8);
+        assert_eq!(num!(de: ordinal: achtes), 8i8);
+        assert_eq!(num!(de: ordinal: sechste), 6i8);
+        assert_eq!(num!(de: ordinal: neunzehnte), 19i8);
+        assert_eq!(num!(de: ordinal: zwanzigste), 20i8);
+        assert_eq!(num!(de: ordinal: einundzwanzigsten), 21i8);
+        assert_eq!(num!(de: ordinal: hunderterste), 101i8);
+        assert_eq!(num!(de: ordinal: zweihundertdritte), 203i16);
+        assert_eq!(num!(de: ordinal: tausendste), 1000i16);
+        assert_eq!(num!(de: ordinal: Dritte), 3i8);
+        assert_eq!(num!(de: ordinal: drei), 3i8);
+
+        assert_eq!(num!(fr: ordinal: deuxième), 2i8);
+        assert_eq!(num!(fr: ordinal: premier), 1i8);
+        assert_eq!(num!(fr: ordinal: première), 1i8);
+        assert_eq!(num!(fr: ordinal: seconde), 2i8);
+        assert_eq!(num!(fr: ordinal: quatrième), 4i8);
+        assert_eq!(num!(fr: ordinal: cinquième), 5i8);
+        assert_eq!(num!(fr: ordinal: neuvième), 9i8);
+        assert_eq!(num!(fr: ordinal: onzième), 11i8);
+        assert_eq!(num!(fr: ordinal: vingt et unième), 21i8);
+        assert_eq!(num!(fr: ordinal: dix-neuvième), 19i8);
+        assert_eq!(num!(fr: ordinal: trentième), 30i8);
+        assert_eq!(num!(fr: ordinal: deux millième), 2000i16);
+        assert_eq!(num!(fr: ordinal: troisiemes), 3i8);
+
+        // Just like in English, only the last word can be an ordinal
+        let mut options = Options::default();
+        options.language = Language::German;
+        assert_eq!(
+            parse_with("dritte", options),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        options.ordinal = true;
+        assert_eq!(parse_with("dritte", options).map(|x| x.value), Ok(3));
+        assert_eq!(
+            parse_with("dritte tausend", options),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        use word_nums_core::{parse_with, Options, ParseError};
+
+        assert_eq!(num!(ordinal: the forty second), 42i8);
+        assert_eq!(num!(ordinal: forty second), 42i8);
+        assert_eq!(num!(ordinal: The First), 1i8);
+        assert_eq!(num!(ordinal: one hundred and twelfth), 112i8);
+        assert_eq!(num!(ordinal: the thousandth), 1000i16);
+        assert_eq!(
+            num!(ordinal: three million five hundred thousandth),
+            3_500_000i32
+        );
+        assert_eq!(num!(loose: the forty two), 42i8);
+
+        let mut options = Options::default();
+        options.ordinal = true;
+        let parse = |input| parse_with(input, options).and_then(word_nums_core::Number::integer);
+        assert_eq!(parse("the twenty first"), Ok(21));
+        assert_eq!(
+            parse("the forty two"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(
+            parse("first hundred"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(
+            parse("forty the second"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse_with("the forty second", Options::default()),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_int_type_for_value() {
+        use word_nums_core::IntType;
+
+        assert_eq!(IntType::for_value(false, 0), Some(IntType::I8));
+        assert_eq!(IntType::for_value(false, 127), Some(IntType::I8));
+        assert_eq!(IntType::for_value(false, 128), Some(IntType::I16));
+        assert_eq!(IntType::for_value(false, -128), Some(IntType::I8));
+        assert_eq!(IntType::for_value(false, -129), Some(IntType::I16));
+        assert_eq!(
+            IntType::for_value(false, i64::MIN.into()),
+            Some(IntType::I64)
+        );
+        assert_eq!(IntType::for_value(false, i128::MIN), Some(IntType::I128));
+        assert_eq!(IntType::for_value(true, 255), Some(IntType::U8));
+        assert_eq!(IntType::for_value(true, 256), Some(IntType::U16));
+        assert_eq!(
+            IntType::for_value(true, u64::MAX.into()),
+            Some(IntType::U64)
+        );
+        assert_eq!(IntType::for_value(true, i128::MAX), Some(IntType::U128));
+        assert_eq!(IntType::for_value(true, -1), None);
+    }
+
+    #[test]
+    fn test_num_duration() {
+        use std::time::Duration;
+
+        assert_eq!(
+            num_duration!(two hours thirty minutes),
+            Duration::from_mins(150)
+        );
+        assert_eq!(
+            num_duration!(one minute thirty seconds),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            num_duration!(thirty seconds one minute),
+            Duration::from_secs(90)
+        );
+        assert_eq!(num_duration!(a day), Duration::from_hours(24));
+        assert_eq!(num_duration!(two weeks), Duration::from_hours(2 * 7 * 24));
+        assert_eq!(
+            num_duration!(one second two hundred fifty milliseconds),
+            Duration::from_millis(1250)
+        );
+        assert_eq!(num_duration!(seven nanoseconds), Duration::from_nanos(7));
+        assert_eq!(
+            num_duration!(one hour minus ten minutes),
+            Duration::from_mins(50)
+        );
+    }
+
+    #[test]
+    fn test_num_duration_const() {
+        use std::time::Duration;
+
+        const TIMEOUT: Duration = num_duration!(five seconds);
+        const INTERVAL: Duration = num_duration!(one minute thirty seconds five milliseconds);
+        static DELAYS: [Duration; 2] = [num_duration!(ten milliseconds), num_duration!(a day)];
+
+        assert_eq!(TIMEOUT, Duration::from_secs(5));
+        assert_eq!(INTERVAL, Duration::from_millis(90_005));
+        assert_eq!(
+            DELAYS,
+            [Duration::from_millis(10), Duration::from_hours(24)]
+        );
+    }
+
+    #[test]
+    fn test_alias() {
+        use word_nums_core::{parse, ParseError};
+
+        assert_eq!(num!(five grand), 5000i16);
+        assert_eq!(num!(a grand), 1000i16);
+        assert_eq!(num!(twenty five grand), 25_000i16);
+        assert_eq!(num!(alias: five grand), 5000i16);
+        assert_eq!(num!(alias: ten k), 10_000i16);
+        assert_eq!(num!(alias: two mil), 2000i16);
+        assert_eq!(num!(alias: six hundo), 600i16);
+        assert_eq!(num!(approx: alias: a couple thou), 2000i16);
+        assert_eq!(parse("ten k"), Err(ParseError::InvalidWord { index: 1 }));
+        assert_eq!(parse("two mil"), Err(ParseError::InvalidWord { index: 1 }));
+    }
+
+    #[test]
+    fn test_strict() {
+        use word_nums_core::{parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(strict: forty two), 42i8);
+        assert_eq!(num!(strict: one hundred twenty three), 123i8);
+        assert_eq!(num!(strict: nineteen hundred eighty four), 1984i16);
+        assert_eq!(num!(strict: two thousand and twelve), 2012i16);
+        assert_eq!(
+            num!(strict: minus ninety nine thousand ninety nine),
+            -99_099i32
+        );
+        assert_eq!(num!(strict: twelve), 12i8);
+        assert_eq!(num!(strict: digits: nineteen eighty four), 1984i16);
+        assert_eq!(num!(strict: de: einundzwanzig), 21i8);
+
+        let mut options = Options::default();
+        options.strict = true;
+        for invalid in [
+            "forty two three",
+            "twelve twelve",
+            "two three",
+            "two twenty",
+            "ten five",
+            "forty zero",
+            "twenty thirty",
+            "one hundred five six",
+        ] {
+            assert_eq!(
+                parse_with(invalid, options),
+                Err(ParseError::InvalidSequence),
+                "{invalid}"
+            );
+        }
+
+        options.language = Language::German;
+        assert_eq!(parse_with("zwei drei", options).map(|x| x.value), Ok(5));
+    }
+
+    #[test]
+    fn test_prog() {
+        use word_nums_core::{parse, parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(prog: null), 0i8);
+        assert_eq!(num!(prog: nil), 0i8);
+        assert_eq!(num!(prog: NULL), 0i8);
+        assert_eq!(num!(u32: prog: null), 0u32);
+        assert_eq!(num!(prog: minus null), 0i8);
+        assert_eq!(num!(de: null), 0i8);
+        assert_eq!(parse("null"), Err(ParseError::InvalidWord { index: 0 }));
+        assert_eq!(parse("nil"), Err(ParseError::InvalidWord { index: 0 }));
+
+        let mut options = Options::default();
+        options.prog = true;
+        assert_eq!(parse_with("null", options).map(|x| x.value), Ok(0));
+        options.language = Language::German;
+        assert_eq!(parse_with("null", options).map(|x| x.value), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_opt() {
+        use word_nums_core::{parse_opt, parse_or};
+
+        assert_eq!(parse_opt("one hundred twenty three"), Some(123));
+        assert_eq!(parse_opt("minus five"), Some(-5));
+        assert_eq!(parse_opt("five dollars"), None);
+        assert_eq!(parse_opt("a quadrillion octillion"), None);
+
+        assert_eq!(parse_or("twelve", -1), 12);
+        assert_eq!(parse_or("twelve apples", -1), -1);
+        assert_eq!(parse_or("two and a half", 0), 0);
+
+        // Input without a number word falls back just like any other error
+        assert_eq!(parse_opt(""), None);
+        assert_eq!(parse_opt("minus"), None);
+        assert_eq!(parse_or("", 7), 7);
+        assert_eq!(parse_or("negative and", 7), 7);
+    }
+
+    #[test]
+    fn test_german() {
+        assert_eq!(num!(de: einundzwanzig), 21i8);
+        assert_eq!(num!(de: ein und zwanzig), 21i8);
+        assert_eq!(num!(de: hundert), 100i8);
+        assert_eq!(num!(de: zweihundertdreiundvierzig), 243i16);
+        assert_eq!(num!(de: Dreitausendeins), 3001i16);
+        assert_eq!(num!(de: neunzehnhundertachtzig), 1980i16);
+        assert_eq!(num!(de: sechzehn), 16i8);
+        assert_eq!(num!(de: dreißig), 30i8);
+        assert_eq!(num!(de: eine Million zweihunderttausend), 1_200_000i32);
+        assert_eq!(num!(de: zwei Billionen), 2_000_000_000_000i64);
+    }
+
+    #[test]
+    fn test_french() {
+        assert_eq!(num!(fr: vingt-et-un), 21i8);
+        assert_eq!(num!(fr: dix-sept), 17i8);
+        assert_eq!(num!(fr: soixante-dix-neuf), 79i8);
+        assert_eq!(num!(fr: cent), 100i8);
+        assert_eq!(num!(fr: deux cents), 200i16);
+        assert_eq!(num!(fr: mille neuf cent nonante), 1990i16);
+        assert_eq!(num!(fr: trois millions), 3_000_000i32);
+    }
+
+    #[test]
+    fn test_swedish() {
+        assert_eq!(num!(sv: tjugoett), 21i8);
+        assert_eq!(num!(sv: tjugo ett), 21i8);
+        assert_eq!(num!(sv: hundra), 100i8);
+        assert_eq!(num!(sv: tusen), 1000i16);
+        assert_eq!(num!(sv: etthundrafemtio), 150i16);
+        assert_eq!(num!(sv: tvåtusen sjuhundra åttiofem), 2785i16);
+        assert_eq!(num!(sv: sexton), 16i8);
+        assert_eq!(num!(sv: en miljon tretusen), 1_003_000i32);
+        assert_eq!(num!(sv: tre miljarder), 3_000_000_000i64);
+        assert_eq!(num!(sv: negativ nittiosju), -97i8);
+    }
+
+    #[test]
+    fn test_vietnamese() {
+        assert_eq!(num!(vi: hai mươi mốt), 21i8);
+        assert_eq!(num!(vi: một trăm), 100i8);
+        assert_eq!(num!(vi: một nghìn), 1000i16);
+        assert_eq!(num!(vi: một ngàn), 1000i16);
+        assert_eq!(num!(vi: mười), 10i8);
+        assert_eq!(num!(vi: mười lăm), 15i8);
+        assert_eq!(num!(vi: mười một), 11i8);
+        assert_eq!(num!(vi: hai mươi tư), 24i8);
+        assert_eq!(num!(vi: ba mươi), 30i8);
+        assert_eq!(num!(vi: một trăm linh năm), 105i8);
+        assert_eq!(num!(vi: hai nghìn không trăm hai mươi sáu), 2026i16);
+        assert_eq!(num!(vi: ba trăm nghìn), 300_000i32);
+        assert_eq!(num!(vi: hai triệu năm trăm nghìn), 2_500_000i32);
+        assert_eq!(num!(vi: bốn tỷ), 4_000_000_000i64);
+        assert_eq!(num!(vi: hai muoi mot), 21i8);
+        assert_eq!(num!(vi: muoi lam), 15i8);
+        assert_eq!(num!(vi: âm chín), -9i8);
+        assert_eq!(num!(vi: Một Trăm), 100i8);
+    }
+
+    #[test]
+    fn test_finnish() {
+        use word_nums_core::{parse_with, Language, Options};
+
+        assert_eq!(num!(fi: kaksikymmentä), 20i8);
+        assert_eq!(num!(fi: sata), 100i8);
+        assert_eq!(num!(fi: tuhat), 1000i16);
+        assert_eq!(num!(fi: nolla), 0i8);
+        assert_eq!(num!(fi: kymmenen), 10i8);
+        assert_eq!(num!(fi: yksitoista), 11i8);
+        assert_eq!(num!(fi: seitsemäntoista), 17i8);
+        assert_eq!(num!(fi: kaksikymmentäyksi), 21i8);
+        assert_eq!(num!(fi: kaksi kymmentä yksi), 21i8);
+        assert_eq!(num!(fi: satakaksikymmentäkolme), 123i8);
+        assert_eq!(num!(fi: kolmesataa), 300i16);
+        assert_eq!(
+            num!(fi: tuhatyhdeksänsataakahdeksankymmentäseitsemän),
+            1987i16
+        );
+        assert_eq!(num!(fi: kymmenentuhatta), 10_000i16);
+        assert_eq!(num!(fi: kaksi miljoonaa viisisataatuhatta), 2_500_000i32);
+        assert_eq!(num!(fi: miljardi), 1_000_000_000i32);
+        assert_eq!(num!(fi: yhdeksankymmenta), 90i8);
+        assert_eq!(num!(fi: miinus neljä), -4i8);
+        assert_eq!(num!(fi: Kaksikymmentä), 20i8);
+
+        // The partitive forms need a count in front of them
+        let mut options = Options::default();
+        options.language = Language::Finnish;
+        assert!(parse_with("sataa", options).is_err());
+        assert!(parse_with("tuhatta", options).is_err());
+        assert_eq!(
+            parse_with("kaksituhatta", options).map(|x| x.value),
+            Ok(2000)
+        );
+    }
+
+    #[test]
+    fn test_localized_signs() {
+        use word_nums_core::{parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(de: minus fünf), -5i8);
+        assert_eq!(num!(de: negativ dreizehn), -13i8);
+        assert_eq!(num!(de: plus zwei), 2u8);
+        assert_eq!(num!(fr: moins cinq), -5i8);
+        assert_eq!(num!(fr: négatif douze), -12i8);
+        assert_eq!(num!(fr: minus cinq), -5i8);
+        assert_eq!(num!(pl: plus dwa), 2u8);
+        assert_eq!(num!(ko: maineoseu sam), -3i8);
+        assert_eq!(num!(tr: eksi beş), -5i8);
+        assert_eq!(num!(tr: artı beş), 5u8);
+        assert_eq!(num!(el: meion pente), -5i8);
+        assert_eq!(num!(he: minus chamesh), -5i8);
+
+        let mut options = Options::default();
+        options.language = Language::French;
+        assert_eq!(
+            parse_with("cinq moins", options),
+            Err(ParseError::UnexpectedSign { index: 1 })
+        );
+        assert_eq!(
+            parse_with("moins cinq", Options::default()),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_num_char_array() {
+        assert_eq!(num_char_array!(one two three), ['1', '2', '3']);
+        assert_eq!(num_char_array!(nine), ['9']);
+        assert_eq!(num_char_array!(zero Zero seven), ['0', '0', '7']);
+        assert_eq!(num_char_array!(digits: oh five), ['0', '5']);
+        assert_eq!(num_char_array!(de: drei vier), ['3', '4']);
+    }
+
+    #[test]
+    fn test_localized_connectors() {
+        use word_nums_core::{parse, parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(one hundred and one), 101i8);
+        assert_eq!(num!(de: ein und zwanzig), 21i8);
+        assert_eq!(num!(fr: vingt et un), 21i8);
+        assert_eq!(num!(el: ekato kai ena), 101i8);
+        assert_eq!(num!(he: esrim ve echad), 21i8);
+
+        let parse_in = |language, input| {
+            let mut options = Options::default();
+            options.language = language;
+            parse_with(input, options).and_then(word_nums_core::Number::integer)
+        };
+        assert_eq!(
+            parse("twenty und one"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse("twenty et one"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse_in(Language::German, "zwanzig and eins"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse_in(Language::French, "vingt und un"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+        assert_eq!(
+            parse_in(Language::Polish, "sto and jeden"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_num_dotted() {
+        assert_eq!(num_dotted!(one dot two dot three dot four), [1u8, 2, 3, 4]);
+        assert_eq!(num_dotted!(forty two), [42u8]);
+        assert_eq!(num_dotted!(one . twenty . three hundred), [1u16, 20, 300]);
+        assert_eq!(
+            num_dotted!(one Dot zero dot seventy thousand),
+            [1u32, 0, 70_000]
+        );
+        assert_eq!(num_dotted!(u16: two dot one), [2u16, 1]);
+        assert_eq!(num_dotted!(plus one dot two), [1u8, 2]);
+        assert_eq!(num_dotted!(i32: minus zero dot two), [0i32, 2]);
+    }
+
+    /// Checks that the multiplier words of a language, given from smallest to
+    /// largest, really are strictly increasing. The summing relies on this
+    /// order to decide which multiplier applies to which count.
+    macro_rules! assert_increasing_multipliers {
+        ($language:expr, [$($word:literal),+ $(,)?]) => {{
+            let mut options = word_nums_core::Options::default();
+            options.language = $language;
+            let values: Vec<i128> = [$($word),+]
+                .iter()
+                .map(|word| {
+                    word_nums_core::parse_with(word, options)
+                        .and_then(word_nums_core::Number::integer)
+                        .unwrap_or_else(|err| panic!("{word:?} is not a number: {err}"))
+                })
+                .collect();
+            for (pair, words) in values.windows(2).zip([$($word),+].windows(2)) {
+                assert!(pair[0] < pair[1], "{} is not larger than {}", words[1], words[0]);
+            }
+            values
+        }};
+    }
+
+    #[test]
+    fn test_word_tables() {
+        use word_nums_core::{parse, Language, ParseError};
+
+        let english = assert_increasing_multipliers!(
+            Language::English,
+            [
+                "hundred",
+                "thousand",
+                "million",
+                "billion",
+                "trillion",
+                "quadrillion",
+                "quintillion",
+                "sextillion",
+                "septillion",
+                "octillion",
+                "nonillion",
+                "decillion",
+                "undecillion",
+            ]
+        );
+
+        // Every short scale name is a thousand times the previous one
+        for pair in english[1..].windows(2) {
+            assert_eq!(pair[0] * 1000, pair[1]);
+        }
+
+        assert_increasing_multipliers!(
+            Language::Polish,
+            ["sto", "tysiąc", "milion", "miliard", "bilion"]
+        );
+        assert_increasing_multipliers!(
+            Language::Korean,
+            ["sip", "baek", "cheon", "man", "eok", "jo", "gyeong"]
+        );
+        assert_increasing_multipliers!(
+            Language::Turkish,
+            ["yüz", "bin", "milyon", "milyar", "trilyon"]
+        );
+        assert_increasing_multipliers!(
+            Language::Greek,
+            ["ekato", "chilia", "ekatommyrio", "disekatommyrio"]
+        );
+        assert_increasing_multipliers!(Language::Hebrew, ["mea", "elef", "milyon", "milyard"]);
+        assert_increasing_multipliers!(
+            Language::German,
+            [
+                "hundert",
+                "tausend",
+                "million",
+                "milliarde",
+                "billion",
+                "billiarde"
+            ]
+        );
+        assert_increasing_multipliers!(Language::French, ["cent", "mille", "million", "milliard"]);
+        assert_increasing_multipliers!(
+            Language::Swedish,
+            ["hundra", "tusen", "miljon", "miljard", "biljon", "biljard"]
+        );
+        assert_increasing_multipliers!(
+            Language::Vietnamese,
+            ["mươi", "trăm", "nghìn", "triệu", "tỷ"]
+        );
+        assert_increasing_multipliers!(
+            Language::Finnish,
+            ["sata", "tuhat", "miljoona", "miljardi", "biljoona"]
+        );
+
+        // The words that are only understood with an option must not collide
+        // with the default words
+        for word in [
+            "couple",
+            "few",
+            "several",
+            "of",
+            "oh",
+            "hundo",
+            "k",
+            "thou",
+            "mil",
+            "first",
+            "second",
+            "twelfth",
+            "hundredth",
+            "thousandth",
+            "the",
+        ] {
+            assert_eq!(
+                parse(word),
+                Err(ParseError::InvalidWord { index: 0 }),
+                "{word}"
+            );
+        }
+
+        for power in (3..=36).step_by(3) {
+            let value = 10i128.pow(power);
+            assert_eq!(parse(&word_nums_core::to_words(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_roman() {
+        use word_nums_core::{parse_with, Options, ParseError};
+
+        assert_eq!(num!(roman: IV), 4i8);
+        assert_eq!(num!(roman: MCMXCIV), 1994i16);
+        assert_eq!(num!(roman: MCMLXXXIV), 1984i16);
+        assert_eq!(num!(roman: xlii), 42i8);
+        assert_eq!(num!(roman: MMMCMXCIX), 3999i16);
+        assert_eq!(num!(roman: I), 1i8);
+
+        let mut options = Options::default();
+        options.roman = true;
+        for input in [
+            "IIII", "VV", "IC", "XM", "MMMM", "IIV", "ABC", "IV X", "", "four",
+        ] {
+            assert_eq!(
+                parse_with(input, options),
+                Err(ParseError::InvalidRoman),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_num_max_min_for() {
+        assert_eq!(num_max_for!(u8), 255u8);
+        assert_eq!(num_max_for!(i16), 32_767i16);
+        assert_eq!(num_max_for!(u128), u128::MAX);
+        assert_eq!(num_max_for!(i64), i64::MAX);
+        assert_eq!(num_max_for!(isize), isize::MAX);
+        assert_eq!(num_min_for!(i8), -128i8);
+        assert_eq!(num_min_for!(i128), i128::MIN);
+        assert_eq!(num_min_for!(u32), 0u32);
+        assert_eq!(num_min_for!(isize), isize::MIN);
+        assert_eq!(num_min_for!(usize), 0usize);
+    }
+
+    #[test]
+    fn test_string_literal_words() {
+        assert_eq!(num!(forty "two"), num!(forty two));
+        assert_eq!(num!("forty two"), 42);
+        assert_eq!(num!("one hundred" "and" twenty three), 123);
+        assert_eq!(num!(minus "five" thousand), -5000);
+        assert_eq!(num!(de: "zweihundert" und "drei"), 203);
+        assert_eq!(num!(u16: "sixty-five thousand"), 65_000_u16);
+        assert_eq!(num!("" seven ""), 7);
+
+        // A leading dash is a minus sign, just like outside of the string
+        assert_eq!(num!("-5"), num!(-5));
+        assert_eq!(num!("-five hundred"), -500i16);
+        assert_eq!(num!(forty "-two"), num!(forty - two));
+    }
+
+    #[test]
+    fn test_largest_multiplier() {
+        use word_nums_core::{largest_multiplier, tokenize, Language, Options};
+
+        let options = Options::default();
+        let largest = |input: &str| largest_multiplier(&tokenize(input), options);
+        assert_eq!(largest("one billion"), Some((1, 1_000_000_000)));
+        assert_eq!(largest("two thousand three hundred"), Some((1, 1000)));
+        assert_eq!(largest("forty two"), None);
+        assert_eq!(largest("zero million"), None);
+        assert_eq!(largest("half of a million"), None);
+
+        let mut options = Options::default();
+        options.language = Language::German;
+        assert_eq!(
+            largest_multiplier(&tokenize("zweitausend"), options),
+            Some((0, 1000))
+        );
+
+        // Numbers that might still fit are parsed as usual
+        assert_eq!(num!(u8: zero million), 0u8);
+        assert_eq!(num!(u8: two hundred fifty five), 255u8);
+        assert_eq!(num!(i8: minus one hundred twenty eight), -128i8);
+    }
+
+    #[test]
+    fn test_num_date() {
+        const EPOCH: (u16, u8, u8) = num_date!(nineteen hundred seventy; one; one);
+
+        assert_eq!(
+            num_date!(two thousand twenty four; three; fifteen),
+            (2024u16, 3u8, 15u8)
+        );
+        assert_eq!(
+            num_date!(nineteen hundred ninety nine; twelve; thirty one),
+            (1999, 12, 31)
+        );
+        assert_eq!(num_date!(zero; twelve; thirty one;), (0, 12, 31));
+        assert_eq!(EPOCH, (1970, 1, 1));
+    }
+
+    #[test]
+    fn test_num_time() {
+        const LUNCH: (u8, u8) = num_time!(noon);
+
+        assert_eq!(LUNCH, (12, 0));
+        assert_eq!(num_time!(midnight), (0u8, 0u8));
+        assert_eq!(num_time!(Midnight), (0, 0));
+        assert_eq!(num_time!(fourteen; thirty), (14, 30));
+        assert_eq!(num_time!(twenty three; fifty nine), (23, 59));
+        assert_eq!(num_time!(seven), (7, 0));
+        assert_eq!(num_time!(noon; fifteen), (12, 15));
+        assert_eq!(num_time!(half past noon), (12, 30));
+        assert_eq!(num_time!(half past midnight), (0, 30));
+        assert_eq!(num_time!(half past three), (3, 30));
+        assert_eq!(num_time!(quarter past nine), (9, 15));
+        assert_eq!(num_time!(quarter to noon), (11, 45));
+        assert_eq!(num_time!(quarter to midnight), (23, 45));
+    }
+
+    #[test]
+    fn test_num_cents() {
+        assert_eq!(num_cents!(three and fifty), 350i16);
+        assert_eq!(num_cents!(three), 300i16);
+        assert_eq!(num_cents!(zero and five), 5i8);
+        assert_eq!(num_cents!(minus three and fifty), -350i16);
+        assert_eq!(num_cents!(minus zero and fifty), -50i8);
+        assert_eq!(num_cents!(gb: one hundred and five and ten), 10_510i16);
+        assert_eq!(num_cents!(u64: two thousand), 200_000u64);
+    }
+
+    #[test]
+    fn test_radix_literals() {
+        assert_eq!(
+            num!(hex_lit: two hundred fifty five),
+            num!(two hundred fifty five)
+        );
+        assert_eq!(num!(hex_lit: u8: two hundred fifty five), 0xffu8);
+        assert_eq!(num!(hex_lit: two hundred fifty five), 0xffi16);
+        assert_eq!(num!(oct_lit: sixty four), 0o100i8);
+        assert_eq!(num!(bin_lit: plus ten), 0b1010u8);
+        assert_eq!(num!(hex_lit: minus one hundred twenty eight), i8::MIN);
+        assert_eq!(num!(hex_lit: u64: twelve), 12u64);
+        assert_eq!(num!(bin_lit: zero), 0i8);
+        assert_eq!(num_grid!(hex_lit: sixteen, seventeen), [[0x10i8, 0x11]]);
+        assert_eq!(num_dotted!(oct_lit: one dot eight), [1u8, 0o10]);
+        assert_eq!(num_percent!(hex_lit: fifty percent of two hundred), 100i8);
+    }
+
+    #[test]
+    fn test_fictional_words() {
+        use word_nums_core::{parse, ParseError};
+
+        assert_eq!(
+            parse("a gazillion"),
+            Err(ParseError::FictionalWord {
+                index: 1,
+                word: "gazillion"
+            })
+        );
+        assert_eq!(
+            parse("umpteen"),
+            Err(ParseError::FictionalWord {
+                index: 0,
+                word: "umpteen"
+            })
+        );
+        assert_eq!(
+            parse("two Zillions"),
+            Err(ParseError::FictionalWord {
+                index: 1,
+                word: "zillion"
+            })
+        );
+        assert_eq!(
+            ParseError::FictionalWord {
+                index: 0,
+                word: "gazillion"
+            }
+            .to_string(),
+            "'gazillion' is not a real number"
+        );
+        assert_eq!(
+            ParseError::FictionalWord {
+                index: 0,
+                word: "umpteen"
+            }
+            .to_string(),
+            "'umpteen' is not a real number"
+        );
+    }
+
+    #[test]
+    fn test_overflow_macros() {
+        assert_eq!(num_u8_saturating!(one thousand), 255u8);
+        assert_eq!(num_u8_saturating!(two hundred fifty five), 255u8);
+        assert_eq!(num_u8_saturating!(minus one), 0u8);
+        assert_eq!(num_i16_saturating!(minus forty thousand), i16::MIN);
+        assert_eq!(num_u8_wrapping!(two hundred fifty six), 0u8);
+        assert_eq!(num_u8_wrapping!(minus one), u8::MAX);
+        assert_eq!(num_i8_wrapping!(one hundred twenty seven), 127i8);
+        assert_eq!(num_i8_wrapping!(one hundred twenty eight), i8::MIN);
+        assert_eq!(num_u128_wrapping!(minus two), u128::MAX - 1);
+    }
+
+    #[test]
+    fn test_phrases() {
+        use word_nums_core::{parse_with, Language, Options};
+
+        assert_eq!(num!(fr: quatre-vingts), 80i8);
+        assert_eq!(num!(fr: quatre vingt un), 81i8);
+        assert_eq!(num!(fr: quatre-vingt-dix-neuf), 99i8);
+        assert_eq!(num!(fr: trois cent quatre-vingt-cinq), 385i16);
+        assert_eq!(num!(fr: Quatre Vingts mille), 80_000i32);
+        assert_eq!(num!(fr: quatre mille), 4000i16);
+
+        assert_eq!(num!(a great gross), 1728i16);
+        assert_eq!(num!(two great gross), 3456i16);
+        assert_eq!(num!(small gross), 120i8);
+        assert_eq!(num!(a gross), 144i16);
+
+        // Phrases are only read in their own language
+        let mut options = Options::default();
+        options.language = Language::French;
+        assert!(parse_with("great gross", options).is_err());
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        use word_nums_core::{parse_prefix, ParseError};
+
+        assert_eq!(parse_prefix("forty two apples"), Ok((42, 9)));
+        assert_eq!(parse_prefix("forty-two apples and pears"), Ok((42, 9)));
+        assert_eq!(
+            parse_prefix("  three hundred  and five days"),
+            Ok((305, 25))
+        );
+        assert_eq!(parse_prefix("minus seven"), Ok((-7, 11)));
+        assert_eq!(parse_prefix("twelve apples twelve"), Ok((12, 6)));
+        assert_eq!(
+            parse_prefix("dreißig"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(
+            parse_prefix("einhundert drei"),
+            Err(ParseError::InvalidWord { index: 0 })
+        );
+        assert_eq!(parse_prefix(""), Err(ParseError::Empty));
+
+        // A lone sign is not a number, so it is no valid prefix either
+        assert_eq!(
+            parse_prefix("minus minus five"),
+            Err(ParseError::UnexpectedSign { index: 1 })
+        );
+        assert_eq!(
+            parse_prefix("minus apples"),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+
+        let input = "four score and seven years ago";
+        let (value, consumed) = parse_prefix(input).unwrap_or_default();
+        assert_eq!((value, &input[consumed..]), (87, " years ago"));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        use word_nums_core::{parse_lenient, ParseError};
+
+        assert_eq!(parse_lenient("forty two"), (42, vec![]));
+        assert_eq!(
+            parse_lenient("um three hundred uh and like five"),
+            (
+                305,
+                vec![
+                    ParseError::InvalidWord { index: 0 },
+                    ParseError::InvalidWord { index: 3 },
+                    ParseError::InvalidWord { index: 5 },
+                ]
+            )
+        );
+        assert_eq!(
+            parse_lenient("twenty erm minus one"),
+            (
+                21,
+                vec![
+                    ParseError::InvalidWord { index: 1 },
+                    ParseError::UnexpectedSign { index: 2 },
+                ]
+            )
+        );
+        assert_eq!(
+            parse_lenient("two thousand thousand"),
+            (2000, vec![ParseError::RepeatedMultiplier])
+        );
+        assert_eq!(
+            parse_lenient("blah blah"),
+            (
+                0,
+                vec![
+                    ParseError::InvalidWord { index: 0 },
+                    ParseError::InvalidWord { index: 1 },
+                    ParseError::Empty,
+                ]
+            )
+        );
+        assert_eq!(parse_lenient(""), (0, vec![ParseError::Empty]));
+
+        // Unknown words are dropped before a valid literal is blamed
+        assert_eq!(
+            parse_lenient("42 apples"),
+            (42, vec![ParseError::InvalidWord { index: 1 }])
+        );
+        assert_eq!(
+            parse_lenient("about 1000 apples"),
+            (
+                1000,
+                vec![
+                    ParseError::InvalidWord { index: 0 },
+                    ParseError::InvalidWord { index: 2 },
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_hex_digits() {
+        use word_nums_core::{parse_with, Options, ParseError};
+
+        assert_eq!(num!(hex: f f), 255i16);
+        assert_eq!(num!(hex: a 0), 160i16);
+        assert_eq!(num!(hex: F F), 255i16);
+        assert_eq!(num!(hex: ff), 255i16);
+        assert_eq!(num!(hex: plus c a f e), 0xcafeu16);
+        assert_eq!(num!(hex: minus one zero), -16i8);
+        assert_eq!(num!(hex: fifteen fifteen), 255i16);
+        assert_eq!(num!(hex: u8: 7 f), 0x7fu8);
+
+        let mut options = Options::default();
+        options.hex = true;
+        let parse = |input| parse_with(input, options).and_then(word_nums_core::Number::integer);
+        assert_eq!(parse("a b c"), Ok(0xabc));
+        assert_eq!(parse("f g"), Err(ParseError::InvalidWord { index: 1 }));
+        assert_eq!(parse("sixteen"), Err(ParseError::InvalidWord { index: 0 }));
+        assert_eq!(
+            parse("f minus"),
+            Err(ParseError::UnexpectedSign { index: 1 })
+        );
+        assert_eq!(
+            parse("ffffffffffffffffffffffffffffffffff"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    number_words_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Small { one, two, three }
+    }
+
+    #[test]
+    fn test_number_words_enum() {
+        number_words_enum! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            #[repr(i16)]
+            pub Mixed { minus one, twenty-one, one hundred }
+        }
+
+        assert_eq!(Small::One as u8, 1);
+        assert_eq!(Small::Two as u8, 2);
+        assert_eq!(Small::Three as u8, 3);
+        assert_ne!(Small::One, Small::Two);
+        assert_eq!(Mixed::MinusOne as i16, -1);
+        assert_eq!(Mixed::TwentyOne as i16, 21);
+        assert_eq!(Mixed::OneHundred as i16, 100);
+    }
+
+    #[test]
+    fn test_num_ratio_reduced() {
+        assert_eq!(num_ratio_reduced!(fifty per hundred), (1i8, 2i8));
+        assert_eq!(num_ratio_reduced!(zero over five), (0i8, 1i8));
+        assert_eq!(num_ratio_reduced!(zero over minus five), (0i8, 1i8));
+        assert_eq!(num_ratio_reduced!(minus four over six), (-2i8, 3i8));
+        assert_eq!(num_ratio_reduced!(four over minus six), (-2i8, 3i8));
+        assert_eq!(num_ratio_reduced!(plus seven over plus seven), (1u8, 1u8));
+        assert_eq!(num_ratio_reduced!(three hundred over seven), (300i16, 7i16));
+        assert_eq!(
+            num_ratio_reduced!(u64: one thousand per ten),
+            (100u64, 1u64)
+        );
+        assert_eq!(num_ratio_reduced!(three out of four), (3i8, 4i8));
+        assert_eq!(num_ratio_reduced!(nine out of twelve), (3i8, 4i8));
+        assert_eq!(num_ratio_reduced!(Three Out Of Four), (3i8, 4i8));
+        assert_eq!(
+            num_ratio_reduced!(u16: two hundred out of one thousand),
+            (1u16, 5u16)
+        );
+    }
+
+    #[test]
+    fn test_leading_dash() {
+        assert_eq!(num!(-five), -5);
+        assert_eq!(num!(-five), -5);
+        assert_eq!(num!(twenty - five), 25);
+        assert_eq!(num!(twenty - five), 25);
+        assert_eq!(num!(-twenty - five), -25);
+        assert_eq!(num!(i64: - one thousand), -1000i64);
+        assert_eq!(num!(digits: -5), -5);
+    }
+
+    #[test]
+    fn test_num_bigdecimal() {
+        use bigdecimal::BigDecimal;
+        use std::str::FromStr;
+
+        let decimal = |x| BigDecimal::from_str(x).expect("Invalid decimal");
+
+        let pi = num_bigdecimal!(
+            three point one four one five nine two six five three five eight nine seven nine
+        );
+        assert_eq!(pi, decimal("3.14159265358979"));
+        assert_ne!(pi, decimal("3.1415926535897"));
+
+        assert_eq!(num_bigdecimal!(zero point one), decimal("0.1"));
+        assert_eq!(
+            num_bigdecimal!(minus two and three quarters),
+            decimal("-2.75")
+        );
+        assert_eq!(
+            num_bigdecimal!(one billion two hundred),
+            BigDecimal::from(1_000_000_200)
+        );
+    }
+
+    #[test]
+    fn test_num_octal_permissions() {
+        assert_eq!(num_octal_permissions!(seven five five), 0o755u32);
+        assert_eq!(num_octal_permissions!(six four four), 0o644u32);
+        assert_eq!(num_octal_permissions!(zero zero zero), 0u32);
+        assert_eq!(num_octal_permissions!(one seven seven seven), 0o1777u32);
+        assert_eq!(num_octal_permissions!(digits: oh seven oh oh), 0o700u32);
+    }
+
+    #[test]
+    fn test_num_complex() {
+        use num_complex::Complex;
+
+        assert_eq!(num_complex!(three plus four i), Complex::new(3i32, 4i32));
+        assert_eq!(num_complex!(four i), Complex::new(0i32, 4i32));
+        assert_eq!(num_complex!(seven), Complex::new(7i32, 0i32));
+        assert_eq!(num_complex!(i), Complex::new(0i32, 1i32));
+        assert_eq!(num_complex!(minus four i), Complex::new(0i32, -4i32));
+        assert_eq!(
+            num_complex!(minus three minus four i),
+            Complex::new(-3i32, -4i32)
+        );
+        assert_eq!(
+            num_complex!(three minus minus four i),
+            Complex::new(3i32, 4i32)
+        );
+        assert_eq!(num_complex!(- two plus i), Complex::new(-2i32, 1i32));
+        assert_eq!(
+            num_complex!(one hundred twenty one plus twenty-five i),
+            Complex::new(121i32, 25i32)
+        );
+        assert_eq!(
+            num_complex!(i64: five billion minus one i),
+            Complex::new(5_000_000_000i64, -1i64)
+        );
+        assert_eq!(num_complex!(u8: two plus three i), Complex::new(2u8, 3u8));
+    }
+
+    #[test]
+    fn test_option_keywords_as_words() {
+        use word_nums_core::{parse, parse_with, Language, Options, ParseError};
+
+        assert_eq!(num!(de: zwei), 2i8);
+        assert_eq!(num!(u8: de: zwei), 2u8);
+        assert_eq!(num!(de: u8: zwei), 2u8);
+        assert_eq!(parse("forty de"), Err(ParseError::InvalidWord { index: 1 }));
+        assert_eq!(parse("de forty"), Err(ParseError::InvalidWord { index: 0 }));
+        assert_eq!(parse("hex two"), Err(ParseError::InvalidWord { index: 0 }));
+
+        let mut options = Options::default();
+        options.language = Language::German;
+        assert_eq!(
+            parse_with("zwei de", options),
+            Err(ParseError::InvalidWord { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_num_pow() {
+        assert_eq!(num_pow!(two to the eighth), 256i16);
+        assert_eq!(num_pow!(ten to the third), 1000i16);
+        assert_eq!(num_pow!(plus two to the eighth), 256u16);
+        assert_eq!(num_pow!(plus two to the seventh), 128u8);
+        assert_eq!(num_pow!(two to the seventh), 128i16);
+        assert_eq!(num_pow!(minus two to the seventh), -128i8);
+        assert_eq!(num_pow!(minus two to the second), 4i8);
+        assert_eq!(num_pow!(five to the zeroth), 1i8);
+        assert_eq!(num_pow!(zero to the zeroth), 1i8);
+        assert_eq!(num_pow!(twelve to first), 12i8);
+        assert_eq!(
+            num_pow!(ten to the eighteenth),
+            1_000_000_000_000_000_000i64
+        );
+        assert_eq!(num_pow!(two to the one hundred twenty sixth), 1i128 << 126);
+        assert_eq!(num_pow!(u32: three to the fourth), 81u32);
+        assert_eq!(num_pow!(hex_lit: sixteen to the second), 0x100i16);
+    }
+
+    #[test]
+    fn test_num_eq() {
+        let () = num_eq!(one thousand; thousand);
+        num_eq!(one thousand; a thousand);
+        num_eq!(a hundred and five; one hundred five);
+        num_eq!(twenty-one; twenty one);
+        num_eq!(a dozen; twelve);
+        num_eq!(fr: quatre vingts; eighty);
+        num_eq!(digits: nineteen eighty four; one thousand nine hundred eighty four);
+        num_eq!(minus zero; zero);
+        num_eq!(two and a half; two point five zero);
+        num_eq!(arith: six times seven; forty two);
+    }
+
+    #[test]
+    fn test_num_in_range() {
+        const _: () = num_in_range!(u8, forty two);
+
+        let () = num_in_range!(u8, two hundred fifty five);
+        num_in_range!(u8, zero);
+        num_in_range!(i8, minus one hundred twenty eight);
+        num_in_range!(u16, de: sechzigtausend);
+        num_in_range!(i128, alias: ten k);
+        num_in_range!(usize, four billion);
+        num_in_range!(u64, arith: two times three);
+    }
+
+    #[test]
+    fn test_num_nonzero() {
+        use std::num::NonZero;
+
+        const LIMIT: NonZero<u16> = num_nonzero!(u16: five hundred);
+        const STEP: NonZero<u8> = num_nonzero_unchecked!(u8: four);
+
+        assert_eq!(LIMIT.get(), 500);
+        assert_eq!(STEP.get(), 4);
+        assert_eq!(num_nonzero!(forty two).get(), 42i8);
+        assert_eq!(num_nonzero!(minus one).get(), -1i8);
+        assert_eq!(num_nonzero!(i64: a million).get(), 1_000_000i64);
+        assert_eq!(num_nonzero!(hex: f f).get(), 255i16);
+        assert_eq!(num_nonzero_unchecked!(forty two).get(), 42i8);
+        assert_eq!(
+            num_nonzero_unchecked!(u128: ten billion).get(),
+            10_000_000_000u128
+        );
+        assert_eq!(Some(num_nonzero_unchecked!(u32: three)), NonZero::new(3u32));
+    }
+
+    #[test]
+    fn test_num_range_ex() {
+        const DIGITS: core::ops::Range<u8> = num_range_ex!(u8: zero to ten);
+
+        assert_eq!(DIGITS, 0..10);
+        assert_eq!(num_range_ex!(one to ten), 1i8..10i8);
+        assert_eq!(num_range_ex!(one to ten).len(), 9);
+        assert!(!num_range_ex!(one to ten).contains(&10));
+        assert_eq!(num_range_ex!(minus five to five hundred), -5i16..500i16);
+        assert_eq!(num_range_ex!(plus one to plus three hundred), 1u16..300u16);
+        assert_eq!(num_range_ex!(three to three), 3i8..3i8);
+        assert_eq!(
+            num_range_ex!(hex_lit: u8: zero to two hundred fifty five),
+            0u8..255u8
+        );
+        assert_eq!(num_range_ex!(i32: one To a thousand).sum::<i32>(), 499_500);
+    }
+
+    #[test]
+    fn test_num_table() {
+        use std::collections::HashMap;
+
+        const NAMES: [(u8, &str); 3] = num_table! { u8: one => "one", two => "two", ten => "ten" };
+
+        assert_eq!(
+            num_table! { one => "a", two => "b" },
+            [(1i8, "a"), (2i8, "b")]
+        );
+        assert_eq!(
+            num_table! { three => 'c', minus one => 'a', a hundred => 'b', },
+            [(3i8, 'c'), (-1i8, 'a'), (100i8, 'b')]
+        );
+        assert_eq!(num_table!(plus one => 1 + 1), [(1u8, 2)]);
+        assert_eq!(
+            num_table!(two thousand => Some("x"), five => None),
+            [(2000i16, Some("x")), (5i16, None)]
+        );
+
+        let map: HashMap<u8, &str> = NAMES.into();
+        assert_eq!(map[&10], "ten");
+
+        let mut total = 0;
+        for (key, name) in NAMES {
+            assert_eq!(map[&key], name);
+            total += key;
+        }
+        assert_eq!(total, 13);
+    }
+
+    #[test]
+    fn test_detect_dialect() {
+        use word_nums_core::{parse_with, Dialect, Options, ParseError};
+
+        assert_eq!(num!(detect: one hundred one), 101i8);
+        assert_eq!(num!(detect: one hundred and one), 101i8);
+        assert_eq!(num!(detect: two thousand and five), 2005i16);
+        assert_eq!(num!(detect: three million two thousand), 3_002_000i32);
+        assert_eq!(
+            num!(detect: two hundred and five thousand three hundred and one),
+            205_301i32
+        );
+        assert_eq!(
+            num!(detect: two hundred five thousand three hundred one),
+            205_301i32
+        );
+
+        let mut options = Options::default();
+        options.dialect = Dialect::Detect;
+        assert_eq!(
+            parse_with("one thousand two hundred", options).map(|x| x.value),
+            Ok(1200)
+        );
+        assert_eq!(
+            parse_with("one hundred and one thousand two hundred one", options),
+            Err(ParseError::InconsistentAnd)
+        );
+        assert_eq!(
+            parse_with("two hundred five thousand and one", options),
+            Err(ParseError::InconsistentAnd)
+        );
+    }
+
+    #[test]
+    fn test_num_bits() {
+        assert_eq!(
+            num_bits!(u8: five),
+            [false, false, false, false, false, true, false, true]
+        );
+        assert_eq!(
+            num_bits!(lsb: u8: five),
+            [true, false, true, false, false, false, false, false]
+        );
+        assert_eq!(num_bits!(minus one), [true; 8]);
+        assert_eq!(num_bits!(zero), [false; 8]);
+        assert_eq!(num_bits!(plus two hundred).len(), 8);
+        assert_eq!(num_bits!(two hundred).len(), 16);
+
+        let bits = num_bits!(lsb: u16: one thousand);
+        let value = bits
+            .iter()
+            .rev()
+            .fold(0u16, |acc, &bit| acc << 1 | u16::from(bit));
+        assert_eq!(value, 1000);
+
+        let bits = num_bits!(i32: minus two);
+        assert_eq!(bits.len(), 32);
+        assert!(!bits[31]);
+        assert!(bits[..31].iter().all(|&bit| bit));
+    }
 }
@@ -0,0 +1,9 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(long: two billion);
+    let _: () = num!(two billion);
+
+    // The note is only emitted once
+    let _ = num!(three trillion);
+}
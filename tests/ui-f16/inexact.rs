@@ -0,0 +1,8 @@
+use word_nums::num_f16;
+
+fn main() {
+    let _ = num_f16!(one point one);
+    let _ = num_f16!(two thousand forty nine);
+    let _ = num_f16!(sixty five thousand five hundred five);
+    let _ = num_f16!(u8: one);
+}
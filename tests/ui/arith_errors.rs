@@ -0,0 +1,7 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(arith: (two apples) times three);
+    let _ = num!(arith: (two plus) times four);
+    let _ = num!(arith: (two and a half) times four);
+}
@@ -0,0 +1,8 @@
+use word_nums::num_bits;
+
+fn main() {
+    let _ = num_bits!(usize: five);
+    let _ = num_bits!(u8: three hundred);
+    let _ = num_bits!(u8: minus one);
+    let _ = num_bits!(lsb:);
+}
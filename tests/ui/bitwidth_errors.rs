@@ -0,0 +1,6 @@
+use word_nums::num_bitwidth;
+
+fn main() {
+    let _ = num_bitwidth!();
+    let _ = num_bitwidth!(minus);
+}
@@ -0,0 +1,7 @@
+use word_nums::num_cents;
+
+fn main() {
+    let _ = num_cents!(three and one hundred);
+    let _ = num_cents!(three and minus five);
+    let _ = num_cents!(three and);
+}
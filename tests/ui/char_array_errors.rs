@@ -0,0 +1,7 @@
+use word_nums::num_char_array;
+
+fn main() {
+    let _ = num_char_array!(one ten two);
+    let _ = num_char_array!(three hundred);
+    let _ = num_char_array!(minus one);
+}
@@ -0,0 +1,8 @@
+use word_nums::num_complex;
+
+fn main() {
+    let _ = num_complex!(three plus four);
+    let _ = num_complex!(three plus apples i);
+    let _ = num_complex!(three billion i);
+    let _ = num_complex!(u8: three minus four i);
+}
@@ -0,0 +1,9 @@
+use word_nums::num_date;
+
+fn main() {
+    let _ = num_date!(two thousand; thirteen; one);
+    let _ = num_date!(two thousand; one; zero);
+    let _ = num_date!(two thousand; one);
+    let _ = num_date!(two thousand; one; two; three);
+    let _ = num_date!(minus five; one; one);
+}
@@ -0,0 +1,8 @@
+use word_nums::num_dotted;
+
+fn main() {
+    let _ = num_dotted!(one dot minus two);
+    let _ = num_dotted!(one dot dot two);
+    let _ = num_dotted!(i32: minus one dot two);
+    let _ = num_dotted!(i8: three dot minus four hundred);
+}
@@ -0,0 +1,7 @@
+use word_nums::num_duration;
+
+fn main() {
+    let _ = num_duration!(one minute two minutes);
+    let _ = num_duration!(five minutes thirty);
+    let _ = num_duration!(minus five seconds);
+}
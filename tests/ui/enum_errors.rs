@@ -0,0 +1,9 @@
+use word_nums::number_words_enum;
+
+number_words_enum! { Duplicate { one, two, one } }
+number_words_enum! { SameValue { one, a } }
+number_words_enum! { Literal { 5 } }
+number_words_enum! { Empty { one,, two } }
+number_words_enum! { Missing one two }
+
+fn main() {}
@@ -0,0 +1,8 @@
+use word_nums::num_eq;
+
+fn main() {
+    num_eq!(one thousand; hundred);
+    num_eq!(two and a half; two);
+    num_eq!(one; one; one);
+    num_eq!(one);
+}
@@ -0,0 +1,7 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(a gazillion);
+    let _ = num!(umpteen);
+    let _ = num!(three Bajillions);
+}
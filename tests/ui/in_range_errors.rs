@@ -0,0 +1,11 @@
+use word_nums::num_in_range;
+
+fn main() {
+    num_in_range!(u8, two hundred fifty six);
+    num_in_range!(u8, minus one);
+    num_in_range!(i8, one hundred twenty eight);
+    num_in_range!(f32, one);
+    num_in_range!(u8 one);
+    num_in_range!(u8, u16: one);
+    num_in_range!(u8, one point five);
+}
@@ -0,0 +1,7 @@
+use word_nums::num_len;
+
+fn main() {
+    let _ = num_len!(minus four);
+    let _ = num_len!(one hundred undecillion);
+    let _ = num_len!();
+}
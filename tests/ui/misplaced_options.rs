@@ -0,0 +1,8 @@
+use word_nums::{num, num_set};
+
+fn main() {
+    let _ = num!(forty de: zwei);
+    let _ = num!(forty de two);
+    let _ = num!(de: zwei u8: drei);
+    let _ = num_set!(one; de: zwei);
+}
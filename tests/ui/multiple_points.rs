@@ -0,0 +1,5 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(three point one point four);
+}
@@ -0,0 +1,7 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(u8: one billion);
+    let _ = num!(i8: minus two thousand five);
+    let _ = num!(u16: three hundred thousand);
+}
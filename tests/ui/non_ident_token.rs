@@ -0,0 +1,6 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(forty @ two);
+    let _ = num!(forty (two));
+}
@@ -0,0 +1,9 @@
+use word_nums::{num_nonzero, num_nonzero_unchecked};
+
+fn main() {
+    let _ = num_nonzero!(zero);
+    let _ = num_nonzero!(u8: minus zero);
+    let _ = num_nonzero!(one point five);
+    let _ = num_nonzero_unchecked!(zero);
+    let _ = num_nonzero_unchecked!(u8: three hundred);
+}
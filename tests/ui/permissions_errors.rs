@@ -0,0 +1,10 @@
+use word_nums::num_octal_permissions;
+
+fn main() {
+    let _ = num_octal_permissions!(seven eight five);
+    let _ = num_octal_permissions!(seven five nine);
+    let _ = num_octal_permissions!(seventy five);
+    let _ = num_octal_permissions!(seven five);
+    let _ = num_octal_permissions!(one two three four five);
+    let _ = num_octal_permissions!(u16: seven five five);
+}
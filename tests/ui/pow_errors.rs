@@ -0,0 +1,10 @@
+use word_nums::num_pow;
+
+fn main() {
+    let _ = num_pow!(ten to the fortieth);
+    let _ = num_pow!(two eighth);
+    let _ = num_pow!(two to the eight);
+    let _ = num_pow!(two to minus second);
+    let _ = num_pow!(u8: two to the eighth);
+    let _ = num_pow!(two and a half to the second);
+}
@@ -0,0 +1,9 @@
+use word_nums::num_range_ex;
+
+fn main() {
+    let _ = num_range_ex!(ten to one);
+    let _ = num_range_ex!(minus one to minus two);
+    let _ = num_range_ex!(one ten);
+    let _ = num_range_ex!(one to);
+    let _ = num_range_ex!(u8: minus one to one);
+}
@@ -0,0 +1,7 @@
+use word_nums::num_ratio_reduced;
+
+fn main() {
+    let _ = num_ratio_reduced!(one over zero);
+    let _ = num_ratio_reduced!(one two);
+    let _ = num_ratio_reduced!(one over);
+}
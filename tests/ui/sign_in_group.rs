@@ -0,0 +1,8 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!((minus five) hundred);
+    let _ = num!(three (hundred (Plus two)));
+    let _ = num!(fr: (moins cinq) cent);
+    let _ = num!(tr: (eksi beş) yüz);
+}
@@ -0,0 +1,6 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(strict: forty two three);
+    let _ = num!(strict: twelve twelve);
+}
@@ -0,0 +1,8 @@
+use word_nums::num;
+
+fn main() {
+    let _ = num!(suffix = "u8": hex_lit: ten);
+    let _ = num!(suffix = "u8": bin_lit: one);
+    let _ = num!(suffix = "u8": two and a half);
+    let _ = num!(suffix = "_i32": one point five);
+}
@@ -0,0 +1,10 @@
+use word_nums::num_table;
+
+fn main() {
+    let _ = num_table! { one => "a", one => "b" };
+    let _ = num_table! { one => "a", a hundred => "b", one hundred => "c" };
+    let _ = num_table! { one "a" };
+    let _ = num_table! { one => };
+    let _ = num_table! { => "a" };
+    let _ = num_table! { one point five => "a" };
+}
@@ -0,0 +1,10 @@
+use word_nums::num_time;
+
+fn main() {
+    let _ = num_time!(twenty four; zero);
+    let _ = num_time!(twelve; sixty);
+    let _ = num_time!(half past twenty four);
+    let _ = num_time!(one; two; three);
+    let _ = num_time!(half past);
+    let _ = num_time!(u16: noon);
+}
@@ -0,0 +1,7 @@
+use word_nums::{num_max_for, num_min_for};
+
+fn main() {
+    let _ = num_max_for!(f32);
+    let _ = num_min_for!();
+    let _ = num_max_for!(u8 u16);
+}
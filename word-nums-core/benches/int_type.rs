@@ -0,0 +1,27 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use word_nums_core::IntType;
+
+fn bench_for_value(c: &mut Criterion) {
+    let values = [
+        ("small", 42),
+        ("medium", 1_000_000),
+        ("large", 1_000_000_000_000_000_000_000),
+        ("negative", -5_000_000_000),
+    ];
+
+    let mut group = c.benchmark_group("for_value");
+    for (name, value) in values {
+        group.bench_function(format!("signed {name}"), |b| {
+            b.iter(|| IntType::for_value(false, black_box(value)));
+        });
+        group.bench_function(format!("unsigned {name}"), |b| {
+            b.iter(|| IntType::for_value(true, black_box(value)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_for_value);
+criterion_main!(benches);
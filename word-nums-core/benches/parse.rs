@@ -0,0 +1,33 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use word_nums_core::parse;
+
+/// The number of words in each input
+const WORDS: usize = 10_000;
+
+/// Builds an input of leading zeros followed by "forty two"
+fn input(zero: &str, forty: &str, two: &str) -> String {
+    let mut words = vec![zero; WORDS - 2];
+    words.extend([forty, two]);
+    words.join(" ")
+}
+
+/// Words that are lowercase already are borrowed, while all others are
+/// lowercased into a new string, which is what every word used to cost
+fn bench_lowercase(c: &mut Criterion) {
+    let inputs = [
+        ("borrowed lowercase", input("zero", "forty", "two")),
+        ("owned uppercase", input("ZERO", "FORTY", "TWO")),
+    ];
+
+    let mut group = c.benchmark_group("parse 10000 words");
+    for (name, input) in &inputs {
+        assert_eq!(parse(input), Ok(42));
+        group.bench_function(*name, |b| b.iter(|| parse(black_box(input))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_lowercase);
+criterion_main!(benches);
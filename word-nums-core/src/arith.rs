@@ -0,0 +1,192 @@
+use crate::{parse_tokens, Number, Options, ParseError, Sign, Token};
+
+/// A binary operator between two whole numbers
+#[derive(Debug, Copy, Clone)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+}
+
+impl Operator {
+    /// Multiplications bind tighter than additions and subtractions
+    const fn precedence(self) -> u8 {
+        match self {
+            Self::Add | Self::Subtract => 0,
+            Self::Multiply => 1,
+        }
+    }
+
+    const fn apply(self, left: i128, right: i128) -> Option<i128> {
+        match self {
+            Self::Add => left.checked_add(right),
+            Self::Subtract => left.checked_sub(right),
+            Self::Multiply => left.checked_mul(right),
+        }
+    }
+}
+
+/// Reads an operator like "times" or "multiplied by" at the index and returns
+/// it along with the number of tokens it spans.
+///
+/// "plus" and "minus" are only operators after an operand. Before one, they
+/// are its sign.
+fn operator_at(tokens: &[Token], index: usize, after_operand: bool) -> Option<(Operator, usize)> {
+    let Some(Token::Word(word)) = tokens.get(index) else {
+        return None;
+    };
+    let is = |x: &str| word.eq_ignore_ascii_case(x);
+
+    if is("times") || is("x") {
+        Some((Operator::Multiply, 1))
+    } else if is("multiplied") {
+        match tokens.get(index + 1) {
+            Some(Token::Word(by)) if by.eq_ignore_ascii_case("by") => Some((Operator::Multiply, 2)),
+            _ => None,
+        }
+    } else if after_operand && is("plus") {
+        Some((Operator::Add, 1))
+    } else if after_operand && is("minus") {
+        Some((Operator::Subtract, 1))
+    } else {
+        None
+    }
+}
+
+fn is_paren(token: &Token, paren: &str) -> bool {
+    matches!(token, Token::Word(word) if *word == paren)
+}
+
+/// Evaluates the tokens as an arithmetic expression, if they contain an
+/// operator or parentheses.
+///
+/// Multiplications are evaluated before additions and subtractions, and
+/// operators of the same precedence are evaluated from left to right. Parts
+/// of the expression can be grouped with the words "(" and ")".
+pub fn parse_expression(tokens: &[Token], options: Options) -> Result<Option<Number>, ParseError> {
+    let is_expression = tokens.iter().enumerate().any(|(index, token)| {
+        is_paren(token, "(")
+            || is_paren(token, ")")
+            || operator_at(tokens, index, index > 0).is_some()
+    });
+    if !is_expression {
+        return Ok(None);
+    }
+
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        options: Options {
+            arith: false,
+            ..options
+        },
+    };
+    let number = parser.expression(0)?;
+
+    // Everything but an unmatched closing parenthesis is read by the parser
+    if parser.position < tokens.len() {
+        return Err(ParseError::UnbalancedParenthesis {
+            index: parser.position,
+        });
+    }
+    Ok(Some(number))
+}
+
+struct Parser<'a, 'b> {
+    tokens: &'a [Token<'b>],
+    position: usize,
+    options: Options,
+}
+
+impl Parser<'_, '_> {
+    fn expression(&mut self, min_precedence: u8) -> Result<Number, ParseError> {
+        let mut left = self.operand()?;
+        while let Some((operator, len)) = operator_at(self.tokens, self.position, true) {
+            if operator.precedence() < min_precedence {
+                break;
+            }
+            self.position += len;
+            let right = self.expression(operator.precedence() + 1)?;
+            left = combine(operator, left, right)?;
+        }
+        Ok(left)
+    }
+
+    /// Reads a single number or a group in parentheses, which may both be
+    /// preceded by a sign
+    fn operand(&mut self) -> Result<Number, ParseError> {
+        let start = self.position;
+
+        let sign = match self.tokens.get(start) {
+            Some(Token::Word(word)) => self.options.language.parse_sign(&word.to_lowercase()),
+            _ => None,
+        };
+        let group_start = if sign.is_some() { start + 1 } else { start };
+        if self
+            .tokens
+            .get(group_start)
+            .is_some_and(|x| is_paren(x, "("))
+        {
+            self.position = group_start + 1;
+            let number = self.expression(0)?;
+            if !self
+                .tokens
+                .get(self.position)
+                .is_some_and(|x| is_paren(x, ")"))
+            {
+                return Err(ParseError::UnbalancedParenthesis { index: group_start });
+            }
+            self.position += 1;
+
+            return Ok(match sign {
+                Some(Sign::Negative) => negate(number)?,
+                _ => number,
+            });
+        }
+
+        while let Some(token) = self.tokens.get(self.position) {
+            if is_paren(token, "(")
+                || is_paren(token, ")")
+                || operator_at(self.tokens, self.position, self.position > start).is_some()
+            {
+                break;
+            }
+            self.position += 1;
+        }
+        if self.position == start {
+            return Err(ParseError::MissingOperand);
+        }
+
+        parse_tokens(&self.tokens[start..self.position], self.options)
+            .map_err(|err| err.offset(start))
+    }
+}
+
+fn combine(operator: Operator, left: Number, right: Number) -> Result<Number, ParseError> {
+    let value = operator
+        .apply(left.integer()?, right.integer()?)
+        .ok_or(ParseError::Overflow)?;
+
+    let sign = match (left.sign, right.sign) {
+        _ if value < 0 => Sign::Negative,
+        (Sign::Positive, Sign::Positive) => Sign::Positive,
+        _ => Sign::Unspecified,
+    };
+    Ok(Number {
+        sign,
+        value,
+        fraction: None,
+    })
+}
+
+fn negate(number: Number) -> Result<Number, ParseError> {
+    let value = number
+        .integer()?
+        .checked_neg()
+        .ok_or(ParseError::Overflow)?;
+    Ok(Number {
+        sign: Sign::Negative,
+        value,
+        fraction: None,
+    })
+}
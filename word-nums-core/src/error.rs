@@ -0,0 +1,174 @@
+use std::fmt;
+
+/// An error that occurred while parsing a number
+///
+/// Errors that are caused by a specific token carry the index of that token.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// A word that is not part of the number vocabulary
+    InvalidWord { index: usize },
+
+    /// A made up number word like "gazillion"
+    FictionalWord { index: usize, word: &'static str },
+
+    /// A sign word anywhere but at the start of the number
+    UnexpectedSign { index: usize },
+
+    /// A literal that is not an unsuffixed integer or decimal
+    InvalidLiteral { index: usize },
+
+    /// A decimal literal without the `float` feature
+    FloatLiteral { index: usize },
+
+    /// A literal next to number words
+    MixedLiteral { index: usize },
+
+    /// A parenthesis in an arithmetic expression without its counterpart
+    UnbalancedParenthesis { index: usize },
+
+    /// A second decimal point, like in "three point one point four"
+    MultiplePoints { index: usize },
+
+    /// No number word at all, like in "" or "minus"
+    Empty,
+
+    /// A fraction anywhere but at the end of the number
+    UnexpectedFraction,
+
+    /// Anything but single digits after a decimal point
+    InvalidDecimal,
+
+    /// A divisor like "half of" anywhere but at the start of the number
+    UnexpectedDivisor,
+
+    /// A multiplier like "hundred" while reading a sequence of digits
+    UnexpectedMultiplier,
+
+    /// The same multiplier twice in a row, like "thousand thousand", or two
+    /// count nouns in a row, like "dozen score"
+    RepeatedMultiplier,
+
+    /// A British English number without the "and" before its last group
+    MissingAnd,
+
+    /// An "and" in some parts of a number but not in others, like in
+    /// "one hundred and one thousand two hundred one"
+    InconsistentAnd,
+
+    /// Two number words next to each other that do not form a number, like
+    /// "two three"
+    InvalidSequence,
+
+    /// The number has a fractional part where a whole number is required
+    NotWhole,
+
+    /// The number does not fit into the internal representation
+    Overflow,
+
+    /// The input is not a single valid Roman numeral
+    InvalidRoman,
+
+    /// An arithmetic operator without a number on one of its sides
+    MissingOperand,
+}
+
+impl ParseError {
+    /// The index of the token that caused the error, if there is one
+    #[must_use]
+    pub const fn index(self) -> Option<usize> {
+        match self {
+            Self::InvalidWord { index }
+            | Self::FictionalWord { index, .. }
+            | Self::UnexpectedSign { index }
+            | Self::InvalidLiteral { index }
+            | Self::FloatLiteral { index }
+            | Self::MixedLiteral { index }
+            | Self::UnbalancedParenthesis { index }
+            | Self::MultiplePoints { index } => Some(index),
+            Self::Empty
+            | Self::UnexpectedFraction
+            | Self::InvalidDecimal
+            | Self::UnexpectedDivisor
+            | Self::UnexpectedMultiplier
+            | Self::RepeatedMultiplier
+            | Self::MissingAnd
+            | Self::InconsistentAnd
+            | Self::InvalidSequence
+            | Self::NotWhole
+            | Self::Overflow
+            | Self::InvalidRoman
+            | Self::MissingOperand => None,
+        }
+    }
+}
+
+impl ParseError {
+    /// Moves the index of the token that caused the error
+    pub(crate) const fn offset(self, by: usize) -> Self {
+        match self {
+            Self::InvalidWord { index } => Self::InvalidWord { index: index + by },
+            Self::FictionalWord { index, word } => Self::FictionalWord {
+                index: index + by,
+                word,
+            },
+            Self::UnexpectedSign { index } => Self::UnexpectedSign { index: index + by },
+            Self::InvalidLiteral { index } => Self::InvalidLiteral { index: index + by },
+            Self::FloatLiteral { index } => Self::FloatLiteral { index: index + by },
+            Self::MixedLiteral { index } => Self::MixedLiteral { index: index + by },
+            Self::UnbalancedParenthesis { index } => {
+                Self::UnbalancedParenthesis { index: index + by }
+            }
+            Self::MultiplePoints { index } => Self::MultiplePoints { index: index + by },
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidWord { .. } => "Invalid token encountered",
+            Self::FictionalWord { word, .. } => return write!(f, "'{word}' is not a real number"),
+            Self::UnexpectedSign { .. } => "Unexpected sign descriptor encountered",
+            Self::InvalidLiteral { .. } => "Expected an unsuffixed integer literal",
+            Self::FloatLiteral { .. } => "Float literals require the float feature",
+            Self::MixedLiteral { .. } => "Number literals can not be mixed with number words",
+            Self::UnbalancedParenthesis { .. } => "This parenthesis is never closed or opened",
+            Self::MultiplePoints { .. } => "A number can not have multiple decimal points",
+            Self::Empty => "Expected a number",
+            Self::UnexpectedFraction => {
+                "Fractions are only allowed at the end of a number, like 'two and a half'"
+            }
+            Self::InvalidDecimal => {
+                "Only single digits can follow the decimal point, like 'three point one four'"
+            }
+            Self::UnexpectedDivisor => {
+                "A division like 'half of' is only allowed at the start of a number"
+            }
+            Self::UnexpectedMultiplier => {
+                "Multipliers like 'hundred' can not be used when reading digits"
+            }
+            Self::RepeatedMultiplier => {
+                "A multiplier like 'thousand' can not directly follow itself, and a count noun like 'dozen' can not follow another one"
+            }
+            Self::MissingAnd => {
+                "British English requires an 'and' before the last part of a number, like 'one hundred and one'"
+            }
+            Self::InconsistentAnd => {
+                "An 'and' has to be used in every part of a number or in none, like 'one hundred and five thousand two hundred and one'"
+            }
+            Self::InvalidSequence => {
+                "Only tens and a unit like 'forty two' can directly follow each other"
+            }
+            Self::NotWhole => "The number is not a whole number",
+            Self::InvalidRoman => {
+                "Expected a single Roman numeral between I and MMMCMXCIX, like 'MCMLXXXIV'"
+            }
+            Self::MissingOperand => "Expected a number on both sides of the operator",
+            Self::Overflow => "Your number literal is too big to fit the internal representation of the word_nums crate or any potentially generated number literal.",
+        })
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::{lang, NumToken, NumType, ParseError, Token};
+#[cfg(feature = "float")]
+use crate::{parse_tokens, Number, Options, Sign};
+
+/// The fractional remainder of a number like "three and a half"
+///
+/// The numerator is always smaller than the denominator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Fraction {
+    pub numerator: NumType,
+    pub denominator: NumType,
+}
+
+impl Fraction {
+    /// Writes the decimal digits of the fraction, without the leading "0."
+    ///
+    /// The digits are computed exactly, so no precision is lost to an
+    /// intermediate float. All supported fractions have denominators that
+    /// are made up of twos and fives, so there is always a finite number of
+    /// digits.
+    pub(crate) fn fmt_decimals(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let denominator = self.denominator.unsigned_abs();
+
+        let mut digits = 1;
+        let mut scale: u128 = 10;
+        while !scale.is_multiple_of(denominator) {
+            digits += 1;
+            scale *= 10;
+        }
+
+        let decimals = self.numerator.unsigned_abs() * (scale / denominator);
+        write!(f, "{decimals:0digits$}")
+    }
+}
+
+/// Removes a trailing fraction like "and a half" or "and three quarters" from
+/// the tokens.
+///
+/// The "and" before a fraction is part of it and can not be dropped, which is
+/// why this has to run before the connectors are filtered out. The returned
+/// fraction may be larger than one, like "five quarters".
+pub fn split_fraction(num_tokens: &mut Vec<NumToken>) -> Result<Option<Fraction>, ParseError> {
+    if let Some(position) = num_tokens.iter().position(|x| matches!(x, NumToken::Point)) {
+        let fraction = decimal_fraction(&num_tokens[position + 1..])?;
+        num_tokens.truncate(position);
+        return Ok(Some(fraction));
+    }
+
+    let Some(position) = num_tokens
+        .iter()
+        .position(|x| matches!(x, NumToken::Fraction(_)))
+    else {
+        return Ok(None);
+    };
+
+    // Fractions are only supported at the very end of a number
+    if position != num_tokens.len() - 1 {
+        return Err(ParseError::UnexpectedFraction);
+    }
+    let [.., NumToken::Literal(numerator), NumToken::Fraction(denominator)] = num_tokens[..] else {
+        return Err(ParseError::UnexpectedFraction);
+    };
+
+    // The fraction either makes up the whole number ("a half"), or it is
+    // introduced by an "and" ("two and a half")
+    let whole_len = position - 1;
+    let keep = match num_tokens[..whole_len] {
+        [] | [NumToken::Sign(_)] => whole_len,
+        [.., NumToken::And] => whole_len - 1,
+        _ => return Err(ParseError::UnexpectedFraction),
+    };
+
+    num_tokens.truncate(keep);
+
+    // After a count noun, the fraction is a fraction of the noun. If that
+    // is whole, like half a dozen, it is added like any other part instead.
+    let numerator = match num_tokens.last() {
+        Some(NumToken::Collective(value)) => {
+            let numerator = numerator.checked_mul(*value).ok_or(ParseError::Overflow)?;
+            if numerator % denominator == 0 {
+                num_tokens.push(NumToken::Literal(numerator / denominator));
+                return Ok(None);
+            }
+            numerator
+        }
+        _ => numerator,
+    };
+
+    Ok(Some(Fraction {
+        numerator,
+        denominator,
+    }))
+}
+
+/// Reports a fraction that is not a whole number without the `float` feature
+/// at its word, as if the word was not known at all
+#[cfg(not(feature = "float"))]
+pub fn reject_fraction(tokens: &[Token]) -> ParseError {
+    let index = tokens
+        .iter()
+        .rposition(|x| {
+            matches!(x, Token::Word(word)
+                if matches!(lang::en::parse_word(&word.to_lowercase()), Some(NumToken::Fraction(_))))
+        })
+        .unwrap_or_default();
+    ParseError::InvalidWord { index }
+}
+
+/// Parses a number whose fraction is named by its denominator, like "three
+/// whole five tenths" or "twenty five hundredths".
+///
+/// The whole part is separated from the numerator by "whole", and may be left
+/// out. Returns `None` if the number does not end in a denominator, so that it
+/// can be parsed as usual instead.
+#[cfg(feature = "float")]
+pub fn parse_named_fraction(
+    tokens: &[Token],
+    options: Options,
+) -> Result<Option<Number>, ParseError> {
+    let Some((Token::Word(last), rest)) = tokens.split_last() else {
+        return Ok(None);
+    };
+    let Some(denominator) = lang::en::parse_denominator_word(&last.to_lowercase()) else {
+        return Ok(None);
+    };
+
+    let options = Options {
+        fractions: false,
+        ..options
+    };
+    let position = rest
+        .iter()
+        .position(|x| matches!(x, Token::Word(word) if word.eq_ignore_ascii_case("whole")));
+    let (whole, start) = match position {
+        Some(position) => (
+            Some(parse_tokens(&rest[..position], options)?),
+            position + 1,
+        ),
+        None => (None, 0),
+    };
+
+    let numerator = parse_tokens(&rest[start..], options).map_err(|err| err.offset(start))?;
+    let sign = match whole {
+        Some(_) if numerator.sign != Sign::Unspecified => {
+            return Err(ParseError::UnexpectedSign { index: start });
+        }
+        Some(whole) => whole.sign,
+        None => numerator.sign,
+    };
+    let numerator = numerator.integer()?.unsigned_abs();
+    let whole = whole.map_or(Ok(0), Number::integer)?.unsigned_abs();
+
+    // After a whole part, only a proper fraction makes sense, while "twelve
+    // tenths" on its own is just like "five quarters"
+    let denominator_abs = denominator.unsigned_abs();
+    if position.is_some() && numerator >= denominator_abs {
+        return Err(ParseError::UnexpectedFraction);
+    }
+    let value = whole
+        .checked_add(numerator / denominator_abs)
+        .and_then(|x| NumType::try_from(x).ok())
+        .ok_or(ParseError::Overflow)?;
+    let numerator =
+        NumType::try_from(numerator % denominator_abs).map_err(|_| ParseError::Overflow)?;
+
+    Ok(Some(Number {
+        sign,
+        value: if matches!(sign, Sign::Negative) {
+            -value
+        } else {
+            value
+        },
+        fraction: Some(Fraction {
+            numerator,
+            denominator,
+        }),
+    }))
+}
+
+/// Reads the single digits after a decimal point, like the "one four" in
+/// "three point one four"
+///
+/// The fraction is not reduced, so that trailing zeros are kept when it is
+/// written out again.
+fn decimal_fraction(digits: &[NumToken]) -> Result<Fraction, ParseError> {
+    if digits.is_empty() {
+        return Err(ParseError::InvalidDecimal);
+    }
+
+    let mut numerator: NumType = 0;
+    let mut denominator: NumType = 1;
+    for digit in digits {
+        let NumToken::Literal(digit @ 0..=9) = *digit else {
+            return Err(ParseError::InvalidDecimal);
+        };
+        numerator = numerator
+            .checked_mul(10)
+            .and_then(|x| x.checked_add(digit))
+            .ok_or(ParseError::Overflow)?;
+        denominator = denominator.checked_mul(10).ok_or(ParseError::Overflow)?;
+    }
+
+    Ok(Fraction {
+        numerator,
+        denominator,
+    })
+}
@@ -0,0 +1,73 @@
+use crate::{lang, NumToken, NumType, Number, Options, ParseError, Sign, Token};
+
+/// Reads a token as one or more hexadecimal digits. Words and literals made up
+/// of only hexadecimal digits like "f", "ff" or `0` contribute each of them,
+/// while number words stand for a single digit up to fifteen.
+fn parse_digits(token: Token, options: Options) -> Option<Vec<NumType>> {
+    let text = match token {
+        Token::Word(text) | Token::Literal(text) => text,
+    };
+    if let Some(digits) = text
+        .chars()
+        .map(|c| c.to_digit(16).map(NumType::from))
+        .collect::<Option<Vec<_>>>()
+    {
+        return Some(digits);
+    }
+
+    let Token::Word(word) = token else {
+        return None;
+    };
+    let word = word.to_lowercase();
+    match options
+        .language
+        .parse_word(&word)
+        .or_else(|| lang::en::parse_digit_word(&word))?
+    {
+        NumToken::Literal(value) if (0..16).contains(&value) => Some(vec![value]),
+        _ => None,
+    }
+}
+
+/// Parses the tokens as a sequence of hexadecimal digits, like "f f" for 255
+pub fn parse_tokens(tokens: &[Token], options: Options) -> Result<Number, ParseError> {
+    let mut sign = Sign::Unspecified;
+    let mut value: NumType = 0;
+    let mut has_digits = false;
+    for (index, token) in tokens.iter().enumerate() {
+        if let Token::Word(word) = *token {
+            if let Some(parsed_sign) = options.language.parse_sign(&word.to_lowercase()) {
+                if index != 0 {
+                    return Err(ParseError::UnexpectedSign { index });
+                }
+                sign = parsed_sign;
+                continue;
+            }
+        }
+
+        let digits = parse_digits(*token, options).ok_or(match token {
+            Token::Word(_) => ParseError::InvalidWord { index },
+            Token::Literal(_) => ParseError::InvalidLiteral { index },
+        })?;
+        has_digits = true;
+        for digit in digits {
+            value = value
+                .checked_mul(16)
+                .and_then(|x| x.checked_add(digit))
+                .ok_or(ParseError::Overflow)?;
+        }
+    }
+
+    if !has_digits {
+        return Err(ParseError::Empty);
+    }
+
+    if matches!(sign, Sign::Negative) {
+        value = -value;
+    }
+    Ok(Number {
+        sign,
+        value,
+        fraction: None,
+    })
+}
@@ -0,0 +1,128 @@
+use crate::NumType;
+
+/// An integer type that a number can be explicitly annotated with
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IntType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+impl IntType {
+    /// Looks up a type by its Rust name, like `u8`
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "i8" => Some(Self::I8),
+            "i16" => Some(Self::I16),
+            "i32" => Some(Self::I32),
+            "i64" => Some(Self::I64),
+            "i128" => Some(Self::I128),
+            "isize" => Some(Self::Isize),
+            "u8" => Some(Self::U8),
+            "u16" => Some(Self::U16),
+            "u32" => Some(Self::U32),
+            "u64" => Some(Self::U64),
+            "u128" => Some(Self::U128),
+            "usize" => Some(Self::Usize),
+            _ => None,
+        }
+    }
+
+    /// The Rust name of the type, like `u8`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+            Self::Isize => "isize",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+            Self::Usize => "usize",
+        }
+    }
+
+    /// Whether the value fits into this type
+    #[must_use]
+    pub fn contains(self, value: NumType) -> bool {
+        match self {
+            Self::I8 => i8::try_from(value).is_ok(),
+            Self::I16 => i16::try_from(value).is_ok(),
+            Self::I32 => i32::try_from(value).is_ok(),
+            Self::I64 => i64::try_from(value).is_ok(),
+            Self::I128 => true,
+            Self::Isize => isize::try_from(value).is_ok(),
+            Self::U8 => u8::try_from(value).is_ok(),
+            Self::U16 => u16::try_from(value).is_ok(),
+            Self::U32 => u32::try_from(value).is_ok(),
+            Self::U64 => u64::try_from(value).is_ok(),
+            Self::U128 => u128::try_from(value).is_ok(),
+            Self::Usize => usize::try_from(value).is_ok(),
+        }
+    }
+
+    /// Finds the smallest signed or unsigned type that the value fits into by
+    /// comparing it against the bounds of each type. A negative value does not
+    /// fit into any unsigned type.
+    #[must_use]
+    pub fn for_value(unsigned: bool, value: NumType) -> Option<Self> {
+        if unsigned {
+            let value = u128::try_from(value).ok()?;
+            Some(if value <= u8::MAX.into() {
+                Self::U8
+            } else if value <= u16::MAX.into() {
+                Self::U16
+            } else if value <= u32::MAX.into() {
+                Self::U32
+            } else if value <= u64::MAX.into() {
+                Self::U64
+            } else {
+                Self::U128
+            })
+        } else {
+            // The complement of a negative value is below the maximum of a
+            // type exactly if the value is above its minimum
+            let magnitude = if value < 0 { !value } else { value };
+            Some(if magnitude <= i8::MAX.into() {
+                Self::I8
+            } else if magnitude <= i16::MAX.into() {
+                Self::I16
+            } else if magnitude <= i32::MAX.into() {
+                Self::I32
+            } else if magnitude <= i64::MAX.into() {
+                Self::I64
+            } else {
+                Self::I128
+            })
+        }
+    }
+
+    /// Finds the smallest signed or unsigned type that all of the values fit into
+    pub fn smallest_fit(unsigned: bool, values: impl Iterator<Item = NumType>) -> Option<Self> {
+        let candidates = if unsigned {
+            [Self::U8, Self::U16, Self::U32, Self::U64, Self::U128]
+        } else {
+            [Self::I8, Self::I16, Self::I32, Self::I64, Self::I128]
+        };
+
+        let (min, max) = values.fold((0, 0), |(min, max), x| (x.min(min), x.max(max)));
+        candidates
+            .into_iter()
+            .find(|x| x.contains(min) && x.contains(max))
+    }
+}
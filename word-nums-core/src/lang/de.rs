@@ -0,0 +1,116 @@
+use crate::{NumToken, Sign};
+
+/// The German number words. Larger numbers are compounds of these, so the
+/// table is also used to split a word like "einhundertzwei" into its parts.
+const WORDS: [(&str, NumToken); 51] = [
+    ("null", NumToken::Literal(0)),
+    ("ein", NumToken::Literal(1)),
+    ("eins", NumToken::Literal(1)),
+    ("eine", NumToken::Literal(1)),
+    ("zwei", NumToken::Literal(2)),
+    ("zwo", NumToken::Literal(2)),
+    ("drei", NumToken::Literal(3)),
+    ("vier", NumToken::Literal(4)),
+    ("fünf", NumToken::Literal(5)),
+    ("sechs", NumToken::Literal(6)),
+    ("sieben", NumToken::Literal(7)),
+    ("acht", NumToken::Literal(8)),
+    ("neun", NumToken::Literal(9)),
+    ("zehn", NumToken::Literal(10)),
+    ("elf", NumToken::Literal(11)),
+    ("zwölf", NumToken::Literal(12)),
+    ("dreizehn", NumToken::Literal(13)),
+    ("vierzehn", NumToken::Literal(14)),
+    ("fünfzehn", NumToken::Literal(15)),
+    ("sechzehn", NumToken::Literal(16)),
+    ("siebzehn", NumToken::Literal(17)),
+    ("achtzehn", NumToken::Literal(18)),
+    ("neunzehn", NumToken::Literal(19)),
+    ("zwanzig", NumToken::Literal(20)),
+    ("dreißig", NumToken::Literal(30)),
+    ("dreissig", NumToken::Literal(30)),
+    ("vierzig", NumToken::Literal(40)),
+    ("fünfzig", NumToken::Literal(50)),
+    ("sechzig", NumToken::Literal(60)),
+    ("siebzig", NumToken::Literal(70)),
+    ("achtzig", NumToken::Literal(80)),
+    ("neunzig", NumToken::Literal(90)),
+    ("hundert", NumToken::Multiplier(100)),
+    ("tausend", NumToken::Multiplier(1000)),
+    // German uses the long scale, so a "Billion" is a million millions
+    ("million", NumToken::Multiplier(1_000_000)),
+    ("millionen", NumToken::Multiplier(1_000_000)),
+    ("milliarde", NumToken::Multiplier(1_000_000_000)),
+    ("milliarden", NumToken::Multiplier(1_000_000_000)),
+    ("billion", NumToken::Multiplier(1_000_000_000_000)),
+    ("billionen", NumToken::Multiplier(1_000_000_000_000)),
+    ("billiarde", NumToken::Multiplier(1_000_000_000_000_000)),
+    ("billiarden", NumToken::Multiplier(1_000_000_000_000_000)),
+    ("und", NumToken::And),
+    // The umlauts are often replaced when they can't be typed
+    ("fuenf", NumToken::Literal(5)),
+    ("zwoelf", NumToken::Literal(12)),
+    ("fuenfzehn", NumToken::Literal(15)),
+    ("fuenfzig", NumToken::Literal(50)),
+    ("funf", NumToken::Literal(5)),
+    ("zwolf", NumToken::Literal(12)),
+    ("funfzehn", NumToken::Literal(15)),
+    ("funfzig", NumToken::Literal(50)),
+];
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    super::find_word(&WORDS, word)
+}
+
+pub fn split_compound(word: &str) -> Option<Vec<NumToken>> {
+    super::split_words(&WORDS, word)
+}
+
+/// The irregular ordinal stems at the end of a word, along with the cardinals
+/// they are formed from
+const ORDINAL_STEMS: [(&str, &str); 4] = [
+    ("ers", "eins"),
+    ("drit", "drei"),
+    ("sieb", "sieben"),
+    ("ach", "acht"),
+];
+
+/// Converts an ordinal like "dritte" or "einundzwanzigsten" into the cardinal
+/// it is formed from
+pub fn ordinal_to_cardinal(word: &str) -> Option<String> {
+    // Ordinals are inflected like adjectives, as in "der dritte" or "am dritten"
+    let stem = ["er", "es", "en", "em", "e"]
+        .into_iter()
+        .find_map(|ending| word.strip_suffix(ending))?;
+
+    // Up to nineteen, the ordinals end in "t", and in "st" above that
+    [stem.strip_suffix('t'), stem.strip_suffix("st")]
+        .into_iter()
+        .flatten()
+        .find_map(|base| {
+            let cardinal = ORDINAL_STEMS
+                .iter()
+                .find_map(|(ordinal, cardinal)| {
+                    base.strip_suffix(ordinal)
+                        .map(|rest| format!("{rest}{cardinal}"))
+                })
+                .unwrap_or_else(|| base.to_string());
+            split_compound(&cardinal).is_some().then_some(cardinal)
+        })
+}
+
+/// The plural multipliers like "Millionen" always need a count
+pub fn counts_once(word: &str) -> bool {
+    !matches!(
+        word,
+        "millionen" | "milliarden" | "billionen" | "billiarden"
+    )
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "positiv" => Some(Sign::Positive),
+        "negativ" => Some(Sign::Negative),
+        _ => None,
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{NumToken, Sign};
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    match word {
+        "miden" => Some(NumToken::Literal(0)),
+        "ena" | "enas" | "mia" => Some(NumToken::Literal(1)),
+        "dyo" | "dio" => Some(NumToken::Literal(2)),
+        "tria" | "treis" => Some(NumToken::Literal(3)),
+        "tessera" | "tesseris" => Some(NumToken::Literal(4)),
+        "pente" => Some(NumToken::Literal(5)),
+        "exi" | "eksi" => Some(NumToken::Literal(6)),
+        "epta" | "efta" => Some(NumToken::Literal(7)),
+        "okto" | "ochto" => Some(NumToken::Literal(8)),
+        "ennea" | "enia" => Some(NumToken::Literal(9)),
+        "deka" => Some(NumToken::Literal(10)),
+        "enteka" | "endeka" => Some(NumToken::Literal(11)),
+        "dodeka" => Some(NumToken::Literal(12)),
+
+        // The teens are written as one word or as "deka" followed by the digit
+        "dekatria" | "dekatreis" => Some(NumToken::Literal(13)),
+        "dekatessera" | "dekatesseris" => Some(NumToken::Literal(14)),
+        "dekapente" => Some(NumToken::Literal(15)),
+        "dekaexi" | "dekaeksi" => Some(NumToken::Literal(16)),
+        "dekaepta" | "dekaefta" => Some(NumToken::Literal(17)),
+        "dekaokto" | "dekaochto" => Some(NumToken::Literal(18)),
+        "dekaennea" | "dekaenia" => Some(NumToken::Literal(19)),
+
+        "eikosi" => Some(NumToken::Literal(20)),
+        "trianta" => Some(NumToken::Literal(30)),
+        "saranta" => Some(NumToken::Literal(40)),
+        "peninta" => Some(NumToken::Literal(50)),
+        "exinta" | "eksinta" => Some(NumToken::Literal(60)),
+        "evdominta" => Some(NumToken::Literal(70)),
+        "ogdonta" => Some(NumToken::Literal(80)),
+        "eneninta" => Some(NumToken::Literal(90)),
+
+        // Greek has dedicated words for the multiples of one hundred, in the
+        // neuter, masculine and feminine forms
+        "ekato" | "ekaton" => Some(NumToken::Multiplier(100)),
+        "diakosia" | "diakosioi" | "diakosies" => Some(NumToken::Literal(200)),
+        "triakosia" | "triakosioi" | "triakosies" => Some(NumToken::Literal(300)),
+        "tetrakosia" | "tetrakosioi" | "tetrakosies" => Some(NumToken::Literal(400)),
+        "pentakosia" | "pentakosioi" | "pentakosies" => Some(NumToken::Literal(500)),
+        "exakosia" | "exakosioi" | "exakosies" => Some(NumToken::Literal(600)),
+        "eptakosia" | "eptakosioi" | "eptakosies" | "eftakosia" => Some(NumToken::Literal(700)),
+        "oktakosia" | "oktakosioi" | "oktakosies" | "ochtakosia" => Some(NumToken::Literal(800)),
+        "enniakosia" | "enniakosioi" | "enniakosies" => Some(NumToken::Literal(900)),
+
+        // A single thousand is "chilia", all larger counts use "chiliades"
+        "chilia" | "chilioi" | "chilies" | "chiliades" => Some(NumToken::Multiplier(1000)),
+        "ekatommyrio" | "ekatommyria" => Some(NumToken::Multiplier(1_000_000)),
+        "disekatommyrio" | "disekatommyria" => Some(NumToken::Multiplier(1_000_000_000)),
+
+        "kai" => Some(NumToken::And),
+
+        _ => None,
+    }
+}
+
+/// The plural multipliers like "chiliades" always need a count
+pub fn counts_once(word: &str) -> bool {
+    !matches!(word, "chiliades" | "ekatommyria" | "disekatommyria")
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "syn" | "sin" => Some(Sign::Positive),
+        "meion" | "mion" | "plin" => Some(Sign::Negative),
+        _ => None,
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{NumToken, ParseError, Sign};
+
+/// A regional variant of English
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Dialect {
+    /// "and" may be left out, like in "one hundred one"
+    #[default]
+    American,
+
+    /// "and" is required before the last group below a hundred, like in
+    /// "one hundred and one"
+    British,
+
+    /// "and" may be left out, but if it is used, it has to be used everywhere
+    /// the British variant would use it
+    Detect,
+}
+
+/// The naming of the multipliers above a million
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Scale {
+    /// Every multiplier is a thousand times the previous one, like in
+    /// "one billion" for a thousand millions
+    #[default]
+    Short,
+
+    /// Every multiplier is a million times the previous one, like in
+    /// "one thousand million" for a thousand millions
+    Long,
+}
+
+/// Converts the value of a multiplier like "billion" from the short to the
+/// long scale.
+///
+/// The short scale multipliers above a million are `10^(3n + 3)`, while those
+/// of the long scale are `10^(6n)`. Those larger than a sextillion do not fit
+/// into an `i128` in the long scale, so `None` is returned for them.
+pub const fn long_scale(value: i128) -> Option<i128> {
+    if value <= 1_000_000 {
+        return Some(value);
+    }
+    let n = (value.ilog10() - 3) / 3;
+    10_i128.checked_pow(6 * n)
+}
+
+/// Checks that the last group below a hundred is introduced by an "and" if
+/// there is anything larger before it
+pub fn check_british_and(num_tokens: &[NumToken]) -> Result<(), ParseError> {
+    let Some(position) = num_tokens
+        .iter()
+        .rposition(|x| matches!(x, NumToken::Multiplier(_)))
+    else {
+        return Ok(());
+    };
+
+    match num_tokens[position + 1..] {
+        [NumToken::Literal(_), ..] => Err(ParseError::MissingAnd),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that "and" is either left out everywhere or used everywhere the
+/// British variant requires it. That is before the last group below a
+/// hundred, and between "hundred" and the tens or units that follow it.
+pub fn check_consistent_and(num_tokens: &[NumToken]) -> Result<(), ParseError> {
+    if !num_tokens.iter().any(|x| matches!(x, NumToken::And)) {
+        return Ok(());
+    }
+
+    for (index, token) in num_tokens.iter().enumerate() {
+        let NumToken::Multiplier(value) = *token else {
+            continue;
+        };
+        let rest = &num_tokens[index + 1..];
+        if !matches!(rest.first(), Some(NumToken::Literal(_))) {
+            continue;
+        }
+
+        let is_last = !rest
+            .iter()
+            .any(|x| matches!(x, NumToken::Multiplier(_) | NumToken::Collective(_)));
+        if value == 100 || is_last {
+            return Err(ParseError::InconsistentAnd);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that no two number words follow each other unless they are tens
+/// followed by a unit, like "forty two". This rejects numbers like
+/// "forty two three" or "twelve twelve", which would otherwise be summed up.
+pub fn check_strict_sequence(num_tokens: &[NumToken]) -> Result<(), ParseError> {
+    for pair in num_tokens.windows(2) {
+        if let [NumToken::Literal(tens), NumToken::Literal(unit)] = *pair {
+            let is_tens = (20..100).contains(&tens) && tens % 10 == 0;
+            if !is_tens || !(1..10).contains(&unit) {
+                return Err(ParseError::InvalidSequence);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    match word {
+        "zero" => Some(NumToken::Literal(0)),
+        "one" | "a" => Some(NumToken::Literal(1)),
+        "two" => Some(NumToken::Literal(2)),
+        "three" => Some(NumToken::Literal(3)),
+        "four" => Some(NumToken::Literal(4)),
+        "five" => Some(NumToken::Literal(5)),
+        "six" => Some(NumToken::Literal(6)),
+        "seven" => Some(NumToken::Literal(7)),
+        "eight" => Some(NumToken::Literal(8)),
+        "nine" => Some(NumToken::Literal(9)),
+        "ten" => Some(NumToken::Literal(10)),
+        "eleven" => Some(NumToken::Literal(11)),
+        "twelve" => Some(NumToken::Literal(12)),
+        "thirteen" => Some(NumToken::Literal(13)),
+        "fourteen" => Some(NumToken::Literal(14)),
+        "fifteen" => Some(NumToken::Literal(15)),
+        "sixteen" => Some(NumToken::Literal(16)),
+        "seventeen" => Some(NumToken::Literal(17)),
+        "eighteen" => Some(NumToken::Literal(18)),
+        "nineteen" => Some(NumToken::Literal(19)),
+
+        "twenty" => Some(NumToken::Literal(20)),
+        "thirty" => Some(NumToken::Literal(30)),
+        "forty" | "fourty" => Some(NumToken::Literal(40)),
+        "fifty" => Some(NumToken::Literal(50)),
+        "sixty" => Some(NumToken::Literal(60)),
+        "seventy" => Some(NumToken::Literal(70)),
+        "eighty" => Some(NumToken::Literal(80)),
+        "ninety" => Some(NumToken::Literal(90)),
+
+        "hundred" => Some(NumToken::Multiplier(100)),
+        "thousand" | "grand" => Some(NumToken::Multiplier(1000)),
+        "million" => Some(NumToken::Multiplier(1_000_000)),
+        "billion" => Some(NumToken::Multiplier(1_000_000_000)),
+        "trillion" => Some(NumToken::Multiplier(1_000_000_000_000)),
+        "quadrillion" => Some(NumToken::Multiplier(1_000_000_000_000_000)),
+        "quintillion" => Some(NumToken::Multiplier(1_000_000_000_000_000_000)),
+        "sextillion" => Some(NumToken::Multiplier(10_i128.pow(21))),
+        "septillion" => Some(NumToken::Multiplier(10_i128.pow(24))),
+        "octillion" => Some(NumToken::Multiplier(10_i128.pow(27))),
+        "nonillion" => Some(NumToken::Multiplier(10_i128.pow(30))),
+        "decillion" => Some(NumToken::Multiplier(10_i128.pow(33))),
+        "undecillion" => Some(NumToken::Multiplier(10_i128.pow(36))),
+
+        "dozen" | "dozens" => Some(NumToken::Collective(12)),
+        "score" | "scores" => Some(NumToken::Collective(20)),
+        "gross" => Some(NumToken::Collective(144)),
+        "ream" | "reams" => Some(NumToken::Collective(500)),
+
+        "and" => Some(NumToken::And),
+
+        // Fractions of a count noun can be whole, so these are always known
+        "half" | "halves" => Some(NumToken::Fraction(2)),
+        "quarter" | "quarters" => Some(NumToken::Fraction(4)),
+        #[cfg(feature = "float")]
+        "point" => Some(NumToken::Point),
+
+        _ => None,
+    }
+}
+
+/// The names of the denominators that are only understood in fractions mode,
+/// like the "tenths" in "five tenths"
+#[cfg(feature = "float")]
+pub fn parse_denominator_word(word: &str) -> Option<i128> {
+    match word {
+        "tenth" | "tenths" => Some(10),
+        "hundredth" | "hundredths" => Some(100),
+        "thousandth" | "thousandths" => Some(1000),
+        "millionth" | "millionths" => Some(1_000_000),
+        _ => None,
+    }
+}
+
+/// Number words that are made up of several words
+pub const PHRASES: [(&[&str], NumToken); 2] = [
+    // A great gross is a dozen gross
+    (&["great", "gross"], NumToken::Collective(1728)),
+    (&["small", "gross"], NumToken::Collective(120)),
+];
+
+/// Vague quantities and connectors that are only understood in approximate mode
+pub fn parse_approx_word(word: &str) -> Option<NumToken> {
+    match word {
+        // These act like multipliers, so that "a couple" is 1 * 2 and
+        // "a few hundred" can be read as 1 * 3 * 100
+        "couple" => Some(NumToken::Multiplier(2)),
+        "few" => Some(NumToken::Multiplier(3)),
+        "several" => Some(NumToken::Multiplier(7)),
+
+        "half" => Some(NumToken::Divisor(2)),
+        "of" => Some(NumToken::And),
+
+        _ => None,
+    }
+}
+
+/// Abbreviated or colloquial multipliers that are only understood in alias mode
+pub fn parse_alias_word(word: &str) -> Option<NumToken> {
+    match word {
+        "hundo" => Some(NumToken::Multiplier(100)),
+
+        // "mil" can also mean a million or a thousandth of an inch, but it is
+        // only ever read as a thousand here
+        "k" | "thou" | "mil" => Some(NumToken::Multiplier(1000)),
+
+        _ => None,
+    }
+}
+
+/// Words that programmers use for zero, which are only understood in
+/// programming mode
+pub fn parse_prog_word(word: &str) -> Option<NumToken> {
+    match word {
+        "null" | "nil" => Some(NumToken::Literal(0)),
+        _ => None,
+    }
+}
+
+/// The ordinal forms of the number words, like "second" or "hundredth"
+pub fn parse_ordinal_word(word: &str) -> Option<NumToken> {
+    match word {
+        "zeroth" => Some(NumToken::Literal(0)),
+        "first" => Some(NumToken::Literal(1)),
+        "second" => Some(NumToken::Literal(2)),
+        "third" => Some(NumToken::Literal(3)),
+        "fourth" => Some(NumToken::Literal(4)),
+        "fifth" => Some(NumToken::Literal(5)),
+        "sixth" => Some(NumToken::Literal(6)),
+        "seventh" => Some(NumToken::Literal(7)),
+        "eighth" => Some(NumToken::Literal(8)),
+        "ninth" => Some(NumToken::Literal(9)),
+        "tenth" => Some(NumToken::Literal(10)),
+        "eleventh" => Some(NumToken::Literal(11)),
+        "twelfth" => Some(NumToken::Literal(12)),
+        "thirteenth" => Some(NumToken::Literal(13)),
+        "fourteenth" => Some(NumToken::Literal(14)),
+        "fifteenth" => Some(NumToken::Literal(15)),
+        "sixteenth" => Some(NumToken::Literal(16)),
+        "seventeenth" => Some(NumToken::Literal(17)),
+        "eighteenth" => Some(NumToken::Literal(18)),
+        "nineteenth" => Some(NumToken::Literal(19)),
+
+        "twentieth" => Some(NumToken::Literal(20)),
+        "thirtieth" => Some(NumToken::Literal(30)),
+        "fortieth" => Some(NumToken::Literal(40)),
+        "fiftieth" => Some(NumToken::Literal(50)),
+        "sixtieth" => Some(NumToken::Literal(60)),
+        "seventieth" => Some(NumToken::Literal(70)),
+        "eightieth" => Some(NumToken::Literal(80)),
+        "ninetieth" => Some(NumToken::Literal(90)),
+
+        "hundredth" => Some(NumToken::Multiplier(100)),
+        "thousandth" => Some(NumToken::Multiplier(1000)),
+        "millionth" => Some(NumToken::Multiplier(1_000_000)),
+        "billionth" => Some(NumToken::Multiplier(1_000_000_000)),
+        "trillionth" => Some(NumToken::Multiplier(1_000_000_000_000)),
+
+        _ => None,
+    }
+}
+
+/// Whether the word is an ordinal, which allows a leading "the"
+pub fn is_ordinal(word: &str) -> bool {
+    parse_ordinal_word(&word.to_ascii_lowercase()).is_some()
+}
+
+/// Whether the word qualifies the number that follows it, like "exactly"
+pub fn is_qualifier(word: &str) -> bool {
+    ["approximately", "exactly", "roughly", "about"]
+        .iter()
+        .any(|x| x.eq_ignore_ascii_case(word))
+}
+
+/// Finds the made up number word that the word is a form of, like "gazillion"
+/// for "gazillions"
+pub fn fictional_word(word: &str) -> Option<&'static str> {
+    [
+        "gazillion",
+        "bazillion",
+        "bajillion",
+        "kajillion",
+        "gajillion",
+        "jillion",
+        "zillion",
+        "squillion",
+        "umptillion",
+        "umpteen",
+    ]
+    .into_iter()
+    .find(|x| {
+        word.eq_ignore_ascii_case(x)
+            || word
+                .strip_suffix(['s', 'S'])
+                .is_some_and(|word| word.eq_ignore_ascii_case(x))
+    })
+}
+
+/// Words that are only understood when reading a sequence of digits
+pub fn parse_digit_word(word: &str) -> Option<NumToken> {
+    match word {
+        "oh" => Some(NumToken::Literal(0)),
+        _ => None,
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The multipliers above a hundred, from the largest to the smallest. These
+/// have to match the values that `parse_word` gives them.
+const SCALES: [(u128, &str); 12] = [
+    (10_u128.pow(36), "undecillion"),
+    (10_u128.pow(33), "decillion"),
+    (10_u128.pow(30), "nonillion"),
+    (10_u128.pow(27), "octillion"),
+    (10_u128.pow(24), "septillion"),
+    (10_u128.pow(21), "sextillion"),
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1000, "thousand"),
+];
+
+/// The multipliers of the long scale, in which the counts of all multipliers
+/// but "thousand" go up to a million.
+const LONG_SCALES: [(u128, &str); 7] = [
+    (10_u128.pow(36), "sextillion"),
+    (10_u128.pow(30), "quintillion"),
+    (10_u128.pow(24), "quadrillion"),
+    (1_000_000_000_000_000_000, "trillion"),
+    (1_000_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1000, "thousand"),
+];
+
+/// Appends the words for an unsigned number
+pub fn write_words(mut value: u128, scale: Scale, words: &mut Vec<&'static str>) {
+    if value == 0 {
+        words.push(ONES[0]);
+        return;
+    }
+
+    let scales: &[(u128, &str)] = match scale {
+        Scale::Short => &SCALES,
+        Scale::Long => &LONG_SCALES,
+    };
+    for &(multiplier, name) in scales {
+        if value >= multiplier {
+            // Only the largest multiplier can have a count of a thousand or
+            // more, and in the long scale, each count can go up to a million
+            write_words(value / multiplier, scale, words);
+            words.push(name);
+            value %= multiplier;
+        }
+    }
+
+    // The remaining value is below a thousand, so the indices are in range
+    #[allow(clippy::cast_possible_truncation)]
+    let mut value = value as usize;
+    if value >= 100 {
+        words.extend([ONES[value / 100], "hundred"]);
+        value %= 100;
+    }
+    if value >= 20 {
+        words.push(TENS[value / 10]);
+        value %= 10;
+        if value != 0 {
+            words.push(ONES[value]);
+        }
+    } else if value != 0 {
+        words.push(ONES[value]);
+    }
+}
+
+/// The sign words, which are understood in every language
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "plus" | "positive" => Some(Sign::Positive),
+        "minus" | "negative" => Some(Sign::Negative),
+        _ => None,
+    }
+}
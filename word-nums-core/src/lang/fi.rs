@@ -0,0 +1,72 @@
+use crate::{NumToken, Sign};
+
+/// The Finnish number words. Larger numbers are compounds of these, so the
+/// table is also used to split a word like "kaksikymmentäyksi" into its
+/// parts. After a count, the multipliers take their partitive form, as in
+/// "kaksikymmentä" for two tens or "kolmesataa" for three hundred.
+const WORDS: [(&str, NumToken); 38] = [
+    ("nolla", NumToken::Literal(0)),
+    ("yksi", NumToken::Literal(1)),
+    ("kaksi", NumToken::Literal(2)),
+    ("kolme", NumToken::Literal(3)),
+    ("neljä", NumToken::Literal(4)),
+    ("viisi", NumToken::Literal(5)),
+    ("kuusi", NumToken::Literal(6)),
+    ("seitsemän", NumToken::Literal(7)),
+    ("kahdeksan", NumToken::Literal(8)),
+    ("yhdeksän", NumToken::Literal(9)),
+    ("kymmenen", NumToken::Literal(10)),
+    ("yksitoista", NumToken::Literal(11)),
+    ("kaksitoista", NumToken::Literal(12)),
+    ("kolmetoista", NumToken::Literal(13)),
+    ("neljätoista", NumToken::Literal(14)),
+    ("viisitoista", NumToken::Literal(15)),
+    ("kuusitoista", NumToken::Literal(16)),
+    ("seitsemäntoista", NumToken::Literal(17)),
+    ("kahdeksantoista", NumToken::Literal(18)),
+    ("yhdeksäntoista", NumToken::Literal(19)),
+    ("kymmentä", NumToken::Multiplier(10)),
+    ("sata", NumToken::Multiplier(100)),
+    ("sataa", NumToken::Multiplier(100)),
+    ("tuhat", NumToken::Multiplier(1000)),
+    ("tuhatta", NumToken::Multiplier(1000)),
+    ("miljoona", NumToken::Multiplier(1_000_000)),
+    ("miljoonaa", NumToken::Multiplier(1_000_000)),
+    ("miljardi", NumToken::Multiplier(1_000_000_000)),
+    ("miljardia", NumToken::Multiplier(1_000_000_000)),
+    // Finnish uses the long scale, so a "biljoona" is a million millions
+    ("biljoona", NumToken::Multiplier(1_000_000_000_000)),
+    ("biljoonaa", NumToken::Multiplier(1_000_000_000_000)),
+    // The "ä" is often replaced when it can't be typed
+    ("nelja", NumToken::Literal(4)),
+    ("seitseman", NumToken::Literal(7)),
+    ("yhdeksan", NumToken::Literal(9)),
+    ("neljatoista", NumToken::Literal(14)),
+    ("seitsemantoista", NumToken::Literal(17)),
+    ("yhdeksantoista", NumToken::Literal(19)),
+    ("kymmenta", NumToken::Multiplier(10)),
+];
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    super::find_word(&WORDS, word)
+}
+
+pub fn split_compound(word: &str) -> Option<Vec<NumToken>> {
+    super::split_words(&WORDS, word)
+}
+
+/// The partitive multipliers like "sataa" always need a count
+pub fn counts_once(word: &str) -> bool {
+    !matches!(
+        word,
+        "kymmentä" | "kymmenta" | "sataa" | "tuhatta" | "miljoonaa" | "miljardia" | "biljoonaa"
+    )
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "miinus" | "negatiivinen" => Some(Sign::Negative),
+        "positiivinen" => Some(Sign::Positive),
+        _ => None,
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{NumToken, Sign};
+
+/// Number words that are made up of several words. The dash in
+/// "quatre-vingts" separates the words just like whitespace does.
+pub const PHRASES: [(&[&str], NumToken); 2] = [
+    (&["quatre", "vingt"], NumToken::Literal(80)),
+    (&["quatre", "vingts"], NumToken::Literal(80)),
+];
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    match word {
+        "zéro" | "zero" => Some(NumToken::Literal(0)),
+        "un" | "une" => Some(NumToken::Literal(1)),
+        "deux" => Some(NumToken::Literal(2)),
+        "trois" => Some(NumToken::Literal(3)),
+        "quatre" => Some(NumToken::Literal(4)),
+        "cinq" => Some(NumToken::Literal(5)),
+        "six" => Some(NumToken::Literal(6)),
+        "sept" => Some(NumToken::Literal(7)),
+        "huit" => Some(NumToken::Literal(8)),
+        "neuf" => Some(NumToken::Literal(9)),
+        "dix" => Some(NumToken::Literal(10)),
+        "onze" => Some(NumToken::Literal(11)),
+        "douze" => Some(NumToken::Literal(12)),
+        "treize" => Some(NumToken::Literal(13)),
+        "quatorze" => Some(NumToken::Literal(14)),
+        "quinze" => Some(NumToken::Literal(15)),
+        "seize" => Some(NumToken::Literal(16)),
+
+        "vingt" => Some(NumToken::Literal(20)),
+        "trente" => Some(NumToken::Literal(30)),
+        "quarante" => Some(NumToken::Literal(40)),
+        "cinquante" => Some(NumToken::Literal(50)),
+        "soixante" => Some(NumToken::Literal(60)),
+
+        // The Belgian and Swiss words for the tens above sixty
+        "septante" => Some(NumToken::Literal(70)),
+        "huitante" | "octante" => Some(NumToken::Literal(80)),
+        "nonante" => Some(NumToken::Literal(90)),
+
+        "cent" | "cents" => Some(NumToken::Multiplier(100)),
+        "mille" | "mil" => Some(NumToken::Multiplier(1000)),
+        "million" | "millions" => Some(NumToken::Multiplier(1_000_000)),
+        "milliard" | "milliards" => Some(NumToken::Multiplier(1_000_000_000)),
+
+        "et" => Some(NumToken::And),
+
+        _ => None,
+    }
+}
+
+/// Converts an ordinal like "deuxième" or "premières" into the cardinal it is
+/// formed from
+pub fn ordinal_to_cardinal(word: &str) -> Option<String> {
+    let word = word.strip_suffix('s').unwrap_or(word);
+    match word {
+        "premier" | "première" | "premiere" => return Some("un".into()),
+        "second" | "seconde" => return Some("deux".into()),
+        _ => {}
+    }
+
+    // The final "e" of the cardinal is dropped before "ième", like in "quatrième"
+    let stem = word
+        .strip_suffix("ième")
+        .or_else(|| word.strip_suffix("ieme"))?;
+    let cardinal = match stem {
+        "cinqu" => "cinq".into(),
+        "neuv" => "neuf".into(),
+        _ if parse_word(stem).is_some() => stem.into(),
+        _ => format!("{stem}e"),
+    };
+    parse_word(&cardinal).is_some().then_some(cardinal)
+}
+
+/// The plural multipliers like "cents" always need a count
+pub fn counts_once(word: &str) -> bool {
+    !matches!(word, "cents" | "millions" | "milliards")
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "moins" | "négatif" | "negatif" => Some(Sign::Negative),
+        "positif" => Some(Sign::Positive),
+        _ => None,
+    }
+}
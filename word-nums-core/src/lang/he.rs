@@ -0,0 +1,57 @@
+use crate::NumToken;
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    // The connector "ve" is often attached to the following word with an
+    // apostrophe, as in "esrim ve'echad"
+    if let Some(word) = word.strip_prefix("ve'") {
+        return parse_word(word);
+    }
+
+    // The digits also have construct forms like "shloshet", which count
+    // thousands as in "shloshet alafim"
+    match word {
+        "efes" => Some(NumToken::Literal(0)),
+        "echad" | "achad" | "achat" => Some(NumToken::Literal(1)),
+        "shnayim" | "shtayim" | "shnei" | "shtei" => Some(NumToken::Literal(2)),
+        "shlosha" | "shalosh" | "shlosh" | "shloshet" => Some(NumToken::Literal(3)),
+        "arba'a" | "arbaa" | "arba" | "arba'at" | "arbaat" => Some(NumToken::Literal(4)),
+        "chamisha" | "hamisha" | "chamesh" | "hamesh" | "chameshet" | "hameshet" => {
+            Some(NumToken::Literal(5))
+        }
+        "shisha" | "shesh" | "sheshet" => Some(NumToken::Literal(6)),
+        "shiv'a" | "shiva" | "sheva" | "shiv'at" | "shivat" => Some(NumToken::Literal(7)),
+        "shmona" | "shmone" | "shmonat" => Some(NumToken::Literal(8)),
+        "tish'a" | "tisha" | "tesha" | "tish'at" | "tishat" => Some(NumToken::Literal(9)),
+
+        // The teens are the digit followed by "asar" or "esre", like "shlosha asar"
+        "eser" | "asara" | "aseret" | "asar" | "esre" | "esreh" => Some(NumToken::Literal(10)),
+
+        "esrim" => Some(NumToken::Literal(20)),
+        "shloshim" => Some(NumToken::Literal(30)),
+        "arba'im" | "arbaim" => Some(NumToken::Literal(40)),
+        "chamishim" | "hamishim" => Some(NumToken::Literal(50)),
+        "shishim" => Some(NumToken::Literal(60)),
+        "shiv'im" | "shivim" => Some(NumToken::Literal(70)),
+        "shmonim" => Some(NumToken::Literal(80)),
+        "tish'im" | "tishim" => Some(NumToken::Literal(90)),
+
+        // Two hundred and two thousand have their own dual forms
+        "mea" | "me'a" | "meah" | "meot" | "me'ot" => Some(NumToken::Multiplier(100)),
+        "matayim" => Some(NumToken::Literal(200)),
+        "elef" | "alafim" => Some(NumToken::Multiplier(1000)),
+        "alpayim" => Some(NumToken::Literal(2000)),
+
+        "milyon" | "milyonim" => Some(NumToken::Multiplier(1_000_000)),
+        "milyard" | "milyardim" => Some(NumToken::Multiplier(1_000_000_000)),
+
+        "ve" => Some(NumToken::And),
+
+        _ => None,
+    }
+}
+
+/// The plural multipliers like "alafim" always need a count
+pub fn counts_once(word: &str) -> bool {
+    let word = word.strip_prefix("ve'").unwrap_or(word);
+    !matches!(word, "meot" | "me'ot" | "alafim" | "milyonim" | "milyardim")
+}
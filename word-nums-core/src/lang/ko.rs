@@ -0,0 +1,38 @@
+use crate::{NumToken, Sign};
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    match word {
+        "gong" | "yeong" | "young" => Some(NumToken::Literal(0)),
+        "il" => Some(NumToken::Literal(1)),
+        "i" | "ee" => Some(NumToken::Literal(2)),
+        "sam" => Some(NumToken::Literal(3)),
+        "sa" => Some(NumToken::Literal(4)),
+        "o" | "oh" => Some(NumToken::Literal(5)),
+        "yuk" | "ryuk" => Some(NumToken::Literal(6)),
+        "chil" => Some(NumToken::Literal(7)),
+        "pal" => Some(NumToken::Literal(8)),
+        "gu" | "ku" => Some(NumToken::Literal(9)),
+
+        // Multipliers within a group of four digits
+        "sip" | "ship" => Some(NumToken::Multiplier(10)),
+        "baek" | "paek" => Some(NumToken::Multiplier(100)),
+        "cheon" | "chon" => Some(NumToken::Multiplier(1000)),
+
+        // Multipliers between the groups
+        "man" => Some(NumToken::Multiplier(10_000)),
+        "eok" | "ok" => Some(NumToken::Multiplier(100_000_000)),
+        "jo" => Some(NumToken::Multiplier(1_000_000_000_000)),
+        "gyeong" => Some(NumToken::Multiplier(10_000_000_000_000_000)),
+
+        _ => None,
+    }
+}
+
+/// The loanwords for plus and minus
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "peulleoseu" => Some(Sign::Positive),
+        "maineoseu" | "mainuseu" => Some(Sign::Negative),
+        _ => None,
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{NumToken, Sign, Token};
+
+mod de;
+mod el;
+pub mod en;
+mod fi;
+mod fr;
+mod he;
+mod ko;
+mod pl;
+mod sv;
+mod tr;
+mod vi;
+
+/// The language that number words are read in
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Language {
+    #[default]
+    English,
+    Polish,
+    Korean,
+    Turkish,
+    Greek,
+    Hebrew,
+    German,
+    French,
+    Swedish,
+    Vietnamese,
+    Finnish,
+}
+
+impl Language {
+    pub(crate) fn parse_word(self, word: &str) -> Option<NumToken> {
+        match self {
+            Self::English => en::parse_word(word),
+            Self::Polish => pl::parse_word(word),
+            Self::Korean => ko::parse_word(word),
+            Self::Turkish => tr::parse_word(word),
+            Self::Greek => el::parse_word(word),
+            Self::Hebrew => he::parse_word(word),
+            Self::German => de::parse_word(word),
+            Self::French => fr::parse_word(word),
+            Self::Swedish => sv::parse_word(word),
+            Self::Vietnamese => vi::parse_word(word),
+            Self::Finnish => fi::parse_word(word),
+        }
+    }
+
+    /// Splits a compound of several number words, like the German
+    /// "einundzwanzig"
+    pub(crate) fn split_compound(self, word: &str) -> Option<Vec<NumToken>> {
+        match self {
+            Self::German => de::split_compound(&word.to_lowercase()),
+            Self::Swedish => sv::split_compound(&word.to_lowercase()),
+            Self::Finnish => fi::split_compound(&word.to_lowercase()),
+            _ => None,
+        }
+    }
+
+    /// Converts an ordinal like the German "dritte" into the cardinal it is
+    /// formed from. The English ordinals are read as words of their own instead.
+    pub(crate) fn ordinal_to_cardinal(self, word: &str) -> Option<String> {
+        match self {
+            Self::German => de::ordinal_to_cardinal(&word.to_lowercase()),
+            Self::French => fr::ordinal_to_cardinal(&word.to_lowercase()),
+            _ => None,
+        }
+    }
+
+    /// Reads the longest phrase of several words at the start of the tokens,
+    /// like the French "quatre vingts", returning how many tokens it spans
+    pub(crate) fn parse_phrase(self, tokens: &[Token]) -> Option<(usize, NumToken)> {
+        let phrases: &[(&[&str], NumToken)] = match self {
+            Self::English => &en::PHRASES,
+            Self::French => &fr::PHRASES,
+            _ => &[],
+        };
+        phrases
+            .iter()
+            .filter(|(words, _)| {
+                words.len() <= tokens.len()
+                    && words.iter().zip(tokens).all(|(expected, token)| {
+                        matches!(token, Token::Word(word) if word.to_lowercase() == *expected)
+                    })
+            })
+            .max_by_key(|(words, _)| words.len())
+            .map(|(words, token)| (words.len(), *token))
+    }
+
+    /// Reads the sign words of the language, which have to be lowercase. The
+    /// English words are understood in every language.
+    ///
+    /// ```
+    /// use word_nums_core::{Language, Sign};
+    ///
+    /// assert_eq!(Language::French.parse_sign("moins"), Some(Sign::Negative));
+    /// assert_eq!(Language::French.parse_sign("plus"), Some(Sign::Positive));
+    /// assert_eq!(Language::English.parse_sign("moins"), None);
+    /// ```
+    #[must_use]
+    pub fn parse_sign(self, word: &str) -> Option<Sign> {
+        let sign = match self {
+            Self::English | Self::Polish | Self::Hebrew => None,
+            Self::Korean => ko::parse_sign(word),
+            Self::Turkish => tr::parse_sign(word),
+            Self::Greek => el::parse_sign(word),
+            Self::German => de::parse_sign(word),
+            Self::French => fr::parse_sign(word),
+            Self::Swedish => sv::parse_sign(word),
+            Self::Vietnamese => vi::parse_sign(word),
+            Self::Finnish => fi::parse_sign(word),
+        };
+        sign.or_else(|| en::parse_sign(word))
+    }
+
+    /// Whether a multiplier word stands for one of itself when there is no
+    /// count before it, like "hundred" in English but not the Polish "tysięcy"
+    pub(crate) fn counts_once(self, word: &str) -> bool {
+        match self {
+            Self::English | Self::Korean | Self::Turkish | Self::Vietnamese => true,
+            Self::Polish => pl::counts_once(&word.to_lowercase()),
+            Self::Greek => el::counts_once(&word.to_lowercase()),
+            Self::Hebrew => he::counts_once(&word.to_lowercase()),
+            Self::German => de::counts_once(&word.to_lowercase()),
+            Self::French => fr::counts_once(&word.to_lowercase()),
+            Self::Swedish => sv::counts_once(&word.to_lowercase()),
+            Self::Finnish => fi::counts_once(&word.to_lowercase()),
+        }
+    }
+
+    /// Whether the language groups digits by ten thousand instead of by a thousand
+    pub(crate) const fn uses_myriads(self) -> bool {
+        matches!(self, Self::Korean)
+    }
+}
+
+/// Looks up a word in a table of number words
+fn find_word(words: &[(&str, NumToken)], word: &str) -> Option<NumToken> {
+    words
+        .iter()
+        .find(|(text, _)| *text == word)
+        .map(|(_, token)| *token)
+}
+
+/// Splits a compound like the German "dreiundzwanzig" into its words by
+/// repeatedly taking the longest word from the table that the rest starts with
+fn split_words(words: &[(&str, NumToken)], word: &str) -> Option<Vec<NumToken>> {
+    let mut tokens = Vec::new();
+    let mut rest = word;
+    while !rest.is_empty() {
+        let (text, token) = words
+            .iter()
+            .filter(|(text, _)| rest.starts_with(text))
+            .max_by_key(|(text, _)| text.len())?;
+        tokens.push(*token);
+        rest = &rest[text.len()..];
+    }
+    Some(tokens)
+}
@@ -0,0 +1,69 @@
+use crate::NumToken;
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    match word {
+        "zero" => Some(NumToken::Literal(0)),
+        "jeden" | "jedna" | "jedno" => Some(NumToken::Literal(1)),
+        "dwa" | "dwie" => Some(NumToken::Literal(2)),
+        "trzy" => Some(NumToken::Literal(3)),
+        "cztery" => Some(NumToken::Literal(4)),
+        "pięć" => Some(NumToken::Literal(5)),
+        "sześć" => Some(NumToken::Literal(6)),
+        "siedem" => Some(NumToken::Literal(7)),
+        "osiem" => Some(NumToken::Literal(8)),
+        "dziewięć" => Some(NumToken::Literal(9)),
+        "dziesięć" => Some(NumToken::Literal(10)),
+        "jedenaście" => Some(NumToken::Literal(11)),
+        "dwanaście" => Some(NumToken::Literal(12)),
+        "trzynaście" => Some(NumToken::Literal(13)),
+        "czternaście" => Some(NumToken::Literal(14)),
+        "piętnaście" => Some(NumToken::Literal(15)),
+        "szesnaście" => Some(NumToken::Literal(16)),
+        "siedemnaście" => Some(NumToken::Literal(17)),
+        "osiemnaście" => Some(NumToken::Literal(18)),
+        "dziewiętnaście" => Some(NumToken::Literal(19)),
+
+        "dwadzieścia" => Some(NumToken::Literal(20)),
+        "trzydzieści" => Some(NumToken::Literal(30)),
+        "czterdzieści" => Some(NumToken::Literal(40)),
+        "pięćdziesiąt" => Some(NumToken::Literal(50)),
+        "sześćdziesiąt" => Some(NumToken::Literal(60)),
+        "siedemdziesiąt" => Some(NumToken::Literal(70)),
+        "osiemdziesiąt" => Some(NumToken::Literal(80)),
+        "dziewięćdziesiąt" => Some(NumToken::Literal(90)),
+
+        // Polish has dedicated words for every multiple of one hundred
+        "sto" => Some(NumToken::Literal(100)),
+        "dwieście" => Some(NumToken::Literal(200)),
+        "trzysta" => Some(NumToken::Literal(300)),
+        "czterysta" => Some(NumToken::Literal(400)),
+        "pięćset" => Some(NumToken::Literal(500)),
+        "sześćset" => Some(NumToken::Literal(600)),
+        "siedemset" => Some(NumToken::Literal(700)),
+        "osiemset" => Some(NumToken::Literal(800)),
+        "dziewięćset" => Some(NumToken::Literal(900)),
+
+        // The singular, the plural after 2-4 and the genitive plural after 5+
+        "tysiąc" | "tysiące" | "tysięcy" => Some(NumToken::Multiplier(1000)),
+        "milion" | "miliony" | "milionów" => Some(NumToken::Multiplier(1_000_000)),
+        "miliard" | "miliardy" | "miliardów" => Some(NumToken::Multiplier(1_000_000_000)),
+        "bilion" | "biliony" | "bilionów" => Some(NumToken::Multiplier(1_000_000_000_000)),
+
+        _ => None,
+    }
+}
+
+/// Only the singular multipliers like "tysiąc" can be used without a count
+pub fn counts_once(word: &str) -> bool {
+    !matches!(
+        word,
+        "tysiące"
+            | "tysięcy"
+            | "miliony"
+            | "milionów"
+            | "miliardy"
+            | "miliardów"
+            | "biliony"
+            | "bilionów"
+    )
+}
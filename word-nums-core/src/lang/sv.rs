@@ -0,0 +1,74 @@
+use crate::{NumToken, Sign};
+
+/// The Swedish number words. Like in German, larger numbers are usually
+/// written as compounds of these, as in "tjugoett". Norwegian and Danish
+/// numbers are built the same way, so they only need a table of their own.
+const WORDS: [(&str, NumToken); 44] = [
+    ("noll", NumToken::Literal(0)),
+    ("en", NumToken::Literal(1)),
+    ("ett", NumToken::Literal(1)),
+    ("två", NumToken::Literal(2)),
+    ("tre", NumToken::Literal(3)),
+    ("fyra", NumToken::Literal(4)),
+    ("fem", NumToken::Literal(5)),
+    ("sex", NumToken::Literal(6)),
+    ("sju", NumToken::Literal(7)),
+    ("åtta", NumToken::Literal(8)),
+    ("nio", NumToken::Literal(9)),
+    ("tio", NumToken::Literal(10)),
+    ("elva", NumToken::Literal(11)),
+    ("tolv", NumToken::Literal(12)),
+    ("tretton", NumToken::Literal(13)),
+    ("fjorton", NumToken::Literal(14)),
+    ("femton", NumToken::Literal(15)),
+    ("sexton", NumToken::Literal(16)),
+    ("sjutton", NumToken::Literal(17)),
+    ("arton", NumToken::Literal(18)),
+    ("nitton", NumToken::Literal(19)),
+    ("tjugo", NumToken::Literal(20)),
+    ("trettio", NumToken::Literal(30)),
+    ("fyrtio", NumToken::Literal(40)),
+    ("femtio", NumToken::Literal(50)),
+    ("sextio", NumToken::Literal(60)),
+    ("sjuttio", NumToken::Literal(70)),
+    ("åttio", NumToken::Literal(80)),
+    ("nittio", NumToken::Literal(90)),
+    ("hundra", NumToken::Multiplier(100)),
+    ("tusen", NumToken::Multiplier(1000)),
+    // Swedish uses the long scale, so a "biljon" is a million millions
+    ("miljon", NumToken::Multiplier(1_000_000)),
+    ("miljoner", NumToken::Multiplier(1_000_000)),
+    ("miljard", NumToken::Multiplier(1_000_000_000)),
+    ("miljarder", NumToken::Multiplier(1_000_000_000)),
+    ("biljon", NumToken::Multiplier(1_000_000_000_000)),
+    ("biljoner", NumToken::Multiplier(1_000_000_000_000)),
+    ("biljard", NumToken::Multiplier(1_000_000_000_000_000)),
+    ("biljarder", NumToken::Multiplier(1_000_000_000_000_000)),
+    // The "å" is often replaced when it can't be typed
+    ("tva", NumToken::Literal(2)),
+    ("atta", NumToken::Literal(8)),
+    ("attio", NumToken::Literal(80)),
+    ("aatta", NumToken::Literal(8)),
+    ("aattio", NumToken::Literal(80)),
+];
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    super::find_word(&WORDS, word)
+}
+
+pub fn split_compound(word: &str) -> Option<Vec<NumToken>> {
+    super::split_words(&WORDS, word)
+}
+
+/// The plural multipliers like "miljoner" always need a count
+pub fn counts_once(word: &str) -> bool {
+    !matches!(word, "miljoner" | "miljarder" | "biljoner" | "biljarder")
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "positiv" => Some(Sign::Positive),
+        "negativ" => Some(Sign::Negative),
+        _ => None,
+    }
+}
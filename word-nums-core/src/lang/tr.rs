@@ -0,0 +1,43 @@
+use crate::{NumToken, Sign};
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    match word {
+        "sıfır" => Some(NumToken::Literal(0)),
+        "bir" => Some(NumToken::Literal(1)),
+        "iki" => Some(NumToken::Literal(2)),
+        "üç" => Some(NumToken::Literal(3)),
+        "dört" => Some(NumToken::Literal(4)),
+        "beş" => Some(NumToken::Literal(5)),
+        "altı" => Some(NumToken::Literal(6)),
+        "yedi" => Some(NumToken::Literal(7)),
+        "sekiz" => Some(NumToken::Literal(8)),
+        "dokuz" => Some(NumToken::Literal(9)),
+
+        "on" => Some(NumToken::Literal(10)),
+        "yirmi" => Some(NumToken::Literal(20)),
+        "otuz" => Some(NumToken::Literal(30)),
+        "kırk" => Some(NumToken::Literal(40)),
+        "elli" => Some(NumToken::Literal(50)),
+        "altmış" => Some(NumToken::Literal(60)),
+        "yetmiş" => Some(NumToken::Literal(70)),
+        "seksen" => Some(NumToken::Literal(80)),
+        "doksan" => Some(NumToken::Literal(90)),
+
+        // One hundred and one thousand are just "yüz" and "bin", without a count
+        "yüz" => Some(NumToken::Multiplier(100)),
+        "bin" => Some(NumToken::Multiplier(1000)),
+        "milyon" => Some(NumToken::Multiplier(1_000_000)),
+        "milyar" => Some(NumToken::Multiplier(1_000_000_000)),
+        "trilyon" => Some(NumToken::Multiplier(1_000_000_000_000)),
+
+        _ => None,
+    }
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "artı" | "arti" => Some(Sign::Positive),
+        "eksi" => Some(Sign::Negative),
+        _ => None,
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{NumToken, Sign};
+
+pub fn parse_word(word: &str) -> Option<NumToken> {
+    // Every word is also accepted without its diacritics, like "mot" for
+    // "một", since they are often left out when typing
+    match word {
+        "không" | "khong" => Some(NumToken::Literal(0)),
+
+        // After the tens, one, four and five take the forms "mốt", "tư" and
+        // "lăm" or "nhăm", as in "hai mươi mốt"
+        "một" | "mốt" | "mot" => Some(NumToken::Literal(1)),
+        "hai" => Some(NumToken::Literal(2)),
+        "ba" => Some(NumToken::Literal(3)),
+        "bốn" | "tư" | "bon" | "tu" => Some(NumToken::Literal(4)),
+        "năm" | "lăm" | "nhăm" | "nam" | "lam" | "nham" => Some(NumToken::Literal(5)),
+        "sáu" | "sau" => Some(NumToken::Literal(6)),
+        "bảy" | "bẩy" | "bay" => Some(NumToken::Literal(7)),
+        "tám" | "tam" => Some(NumToken::Literal(8)),
+        "chín" | "chin" => Some(NumToken::Literal(9)),
+
+        // "mười" is ten on its own, while "mươi" counts the tens after a digit,
+        // as in "hai mươi". Without the diacritics they are the same word, which
+        // works for both as a multiplier.
+        "mười" => Some(NumToken::Literal(10)),
+        "mươi" | "muoi" => Some(NumToken::Multiplier(10)),
+
+        "trăm" | "tram" => Some(NumToken::Multiplier(100)),
+        "nghìn" | "ngàn" | "nghin" | "ngan" => Some(NumToken::Multiplier(1000)),
+        "triệu" | "trieu" => Some(NumToken::Multiplier(1_000_000)),
+        "tỷ" | "tỉ" | "ty" | "ti" => Some(NumToken::Multiplier(1_000_000_000)),
+
+        // The connector for a missing tens digit, as in "một trăm linh năm"
+        "linh" | "lẻ" | "le" => Some(NumToken::And),
+
+        _ => None,
+    }
+}
+
+pub fn parse_sign(word: &str) -> Option<Sign> {
+    match word {
+        "dương" | "duong" => Some(Sign::Positive),
+        "âm" | "am" => Some(Sign::Negative),
+        _ => None,
+    }
+}
@@ -0,0 +1,1021 @@
+//! The parser behind the `word_nums` macros.
+//!
+//! This crate turns numbers written in words into their values without going
+//! through a procedural macro, which means it can also be used at runtime.
+//! Unlike the macros, the parser never panics: invalid and overflowing inputs
+//! are always reported as a [`ParseError`].
+//!
+//! ```
+//! use word_nums_core::{parse, ParseError};
+//!
+//! assert_eq!(parse("forty two"), Ok(42));
+//! assert_eq!(parse("minus one thousand three hundred thirty seven"), Ok(-1337));
+//! assert_eq!(parse("a quadrillion octillion"), Err(ParseError::Overflow));
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+mod arith;
+mod error;
+mod float;
+mod hex;
+mod int_type;
+mod lang;
+mod literal;
+mod roman;
+
+pub use error::ParseError;
+pub use float::Fraction;
+pub use int_type::IntType;
+pub use lang::en::{Dialect, Scale};
+pub use lang::Language;
+use literal::NumLiteral;
+
+type NumType = i128;
+
+/// Parses an English number into an integer.
+///
+/// Words are separated by whitespace or dashes.
+///
+/// ```
+/// use word_nums_core::parse;
+///
+/// assert_eq!(parse("forty two"), Ok(42));
+/// assert_eq!(parse("a dozen and a half"), Ok(18));
+/// assert_eq!(parse("two dozen and a half"), Ok(30));
+/// assert!(parse("three and a half").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the input is not a valid number, if it does not fit
+/// into an `i128` or if it has a fractional part. Input without a number word,
+/// like "" or "minus", is [`ParseError::Empty`] rather than zero.
+pub fn parse(input: &str) -> Result<i128, ParseError> {
+    parse_with(input, Options::default())?.integer()
+}
+
+/// Parses an English number into an integer, or returns `None` if [`parse`]
+/// would return an error.
+///
+/// ```
+/// use word_nums_core::parse_opt;
+///
+/// assert_eq!(parse_opt("forty two"), Some(42));
+/// assert_eq!(parse_opt("forty tw"), None);
+/// assert_eq!(parse_opt(""), None);
+/// assert_eq!(parse_opt("minus"), None);
+/// ```
+#[must_use]
+pub fn parse_opt(input: &str) -> Option<i128> {
+    parse(input).ok()
+}
+
+/// Parses an English number into an integer, or returns the default if
+/// [`parse`] would return an error.
+///
+/// ```
+/// use word_nums_core::parse_or;
+///
+/// assert_eq!(parse_or("seven", 0), 7);
+/// assert_eq!(parse_or("many", 0), 0);
+/// assert_eq!(parse_or("", 7), 7);
+/// assert_eq!(parse_or("plus", 7), 7);
+/// ```
+#[must_use]
+pub fn parse_or(input: &str, default: i128) -> i128 {
+    parse(input).unwrap_or(default)
+}
+
+/// Writes out a number in English words.
+///
+/// The words can be read back by [`parse`], except for those of `i128::MIN`.
+/// The parser reads the magnitude before applying the sign, and the
+/// magnitude of `i128::MIN` does not fit into an `i128`. Only whole numbers
+/// are written out, so there is no negative zero, even though "minus zero
+/// point zero" reads as `-0.0` with the `float` feature.
+///
+/// ```
+/// use word_nums_core::to_words;
+///
+/// assert_eq!(to_words(-42), "minus forty two");
+/// assert_eq!(to_words(1905), "one thousand nine hundred five");
+/// ```
+#[must_use]
+pub fn to_words(value: i128) -> String {
+    to_words_with(value, Scale::Short)
+}
+
+/// Writes out a number in English words, naming the large multipliers in
+/// the given scale.
+///
+/// English numbers are always read in the short scale, so the words of the
+/// long scale can not be read back by [`parse`].
+///
+/// ```
+/// use word_nums_core::{to_words_with, Scale};
+///
+/// assert_eq!(to_words_with(1_000_000_000, Scale::Short), "one billion");
+/// assert_eq!(to_words_with(1_000_000_000, Scale::Long), "one thousand million");
+/// ```
+#[must_use]
+pub fn to_words_with(value: i128, scale: Scale) -> String {
+    let mut words = Vec::new();
+    if value < 0 {
+        words.push("minus");
+    }
+    lang::en::write_words(value.unsigned_abs(), scale, &mut words);
+    words.join(" ")
+}
+
+/// Writes the value as an integer literal suffixed with the smallest signed
+/// type that it fits into.
+///
+/// This is the literal that `word_nums::num!` expands to for a number without
+/// a sign word or a type option, so build scripts can generate the same code.
+///
+/// ```
+/// use word_nums_core::to_rust_literal;
+///
+/// assert_eq!(to_rust_literal(42), "42i8");
+/// assert_eq!(to_rust_literal(-1000), "-1000i16");
+/// ```
+#[must_use]
+pub fn to_rust_literal(value: i128) -> String {
+    // Every value fits into an i128, so the fallback is never used
+    let int_type = IntType::for_value(false, value).unwrap_or(IntType::I128);
+    format!("{value}{}", int_type.name())
+}
+
+/// Parses a number with the given options.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a valid number or if it does not fit
+/// into an `i128`.
+pub fn parse_with(input: &str, options: Options) -> Result<Number, ParseError> {
+    parse_tokens(&tokenize(input), options)
+}
+
+/// Splits the input into tokens at whitespace and dashes.
+///
+/// Anything starting with a digit is treated as a number literal.
+#[must_use]
+pub fn tokenize(input: &str) -> Vec<Token<'_>> {
+    split_words(input).map(|(_, token)| token).collect()
+}
+
+/// Splits the input into tokens like [`tokenize`] does, along with the byte
+/// offset of each token in the input
+fn split_words(input: &str) -> impl Iterator<Item = (usize, Token<'_>)> {
+    let is_separator = |c: char| c.is_whitespace() || c == '-';
+    input
+        .split_inclusive(is_separator)
+        .scan(0, move |offset, piece| {
+            let start = *offset;
+            *offset += piece.len();
+            Some((start, piece.strip_suffix(is_separator).unwrap_or(piece)))
+        })
+        .filter(|(_, x)| !x.is_empty())
+        .map(|(start, x)| {
+            if x.starts_with(|c: char| c.is_ascii_digit()) {
+                (start, Token::Literal(x))
+            } else {
+                (start, Token::Word(x))
+            }
+        })
+}
+
+/// Parses an English number at the start of the input, returning it along
+/// with the number of bytes that it spans.
+///
+/// The number is the longest run of words at the start that is a valid
+/// number, so parsing stops before the first word that does not belong to it.
+/// A run without a number word, like a lone "minus", is never a valid number.
+/// This allows numbers to be read from larger texts one after another.
+///
+/// ```
+/// use word_nums_core::parse_prefix;
+///
+/// assert_eq!(parse_prefix("forty two apples"), Ok((42, 9)));
+/// assert_eq!(parse_prefix("seven"), Ok((7, 5)));
+/// ```
+///
+/// # Errors
+///
+/// Returns the error of parsing all of the input if no run of words at the
+/// start is a valid number.
+pub fn parse_prefix(input: &str) -> Result<(i128, usize), ParseError> {
+    let (offsets, tokens): (Vec<usize>, Vec<Token>) = split_words(input).unzip();
+    for len in (1..=tokens.len()).rev() {
+        if let Ok(value) =
+            parse_tokens(&tokens[..len], Options::default()).and_then(Number::integer)
+        {
+            let consumed = offsets[len - 1] + token_text(tokens[len - 1]).len();
+            return Ok((value, consumed));
+        }
+    }
+    parse_tokens(&tokens, Options::default())?
+        .integer()
+        .map(|value| (value, 0))
+}
+
+/// Parses an English number as well as possible, returning the best-effort
+/// value along with every error that was skipped on the way.
+///
+/// This never fails outright, which makes it useful for cleaning up sloppy
+/// input like voice transcriptions. Words that caused an error are dropped
+/// one by one until the rest is a valid number, so filler words like "um" are
+/// skipped. Unknown words are always dropped before any of the known ones are
+/// blamed, so "42 apples" keeps the 42. The index of each error refers to the
+/// token in the whole input. If the rest is still invalid without a word to
+/// blame, like "thousand thousand", the longest valid run of words at the
+/// start is used instead.
+/// A fractional part is dropped and reported as [`ParseError::NotWhole`].
+///
+/// ```
+/// use word_nums_core::{parse_lenient, ParseError};
+///
+/// assert_eq!(parse_lenient("forty two"), (42, vec![]));
+/// assert_eq!(
+///     parse_lenient("forty uh two"),
+///     (42, vec![ParseError::InvalidWord { index: 1 }])
+/// );
+/// ```
+#[must_use]
+pub fn parse_lenient(input: &str) -> (i128, Vec<ParseError>) {
+    let mut tokens: Vec<(usize, Token)> = tokenize(input).into_iter().enumerate().collect();
+    let mut errors = Vec::new();
+
+    loop {
+        let kept: Vec<Token> = tokens.iter().map(|(_, token)| *token).collect();
+        let err = match parse_tokens(&kept, Options::default()) {
+            Ok(number) => {
+                if let Err(err) = number.integer() {
+                    errors.push(err);
+                }
+                return (number.value, errors);
+            }
+            Err(err) => err,
+        };
+
+        let blamed = match err {
+            ParseError::InvalidWord { index } | ParseError::FictionalWord { index, .. } => {
+                Some((index, err))
+            }
+            _ => find_unknown_word(&kept).or_else(|| err.index().map(|index| (index, err))),
+        };
+        let Some((index, err)) = blamed else {
+            errors.push(err);
+            let value = (0..kept.len())
+                .rev()
+                .find_map(|len| parse_tokens(&kept[..len], Options::default()).ok())
+                .map_or(0, |number| number.value);
+            return (value, errors);
+        };
+        let (original, _) = tokens.remove(index);
+        errors.push(err.offset(original - index));
+    }
+}
+
+/// Finds the first word that is not a number word on its own, returning its
+/// index along with the error it causes
+fn find_unknown_word(tokens: &[Token]) -> Option<(usize, ParseError)> {
+    tokens.iter().enumerate().find_map(|(index, token)| {
+        match parse_tokens(std::slice::from_ref(token), Options::default()) {
+            Err(err @ (ParseError::InvalidWord { .. } | ParseError::FictionalWord { .. })) => {
+                Some((index, err.offset(index)))
+            }
+            _ => None,
+        }
+    })
+}
+
+const fn token_text(token: Token<'_>) -> &str {
+    match token {
+        Token::Word(text) | Token::Literal(text) => text,
+    }
+}
+
+/// Finds the largest multiplier word in the tokens, returning its index and
+/// value.
+///
+/// Unless the number is written with a zero count or a fraction, it is at
+/// least as large as this multiplier, so this can be used to tell that it will
+/// not fit into a type before parsing all of it. In all other cases `None` is
+/// returned.
+#[must_use]
+pub fn largest_multiplier(tokens: &[Token], options: Options) -> Option<(usize, i128)> {
+    if options.roman || options.hex || options.arith {
+        return None;
+    }
+
+    let mut largest = None;
+    for (index, token) in tokens.iter().enumerate() {
+        let Token::Word(word) = *token else {
+            continue;
+        };
+        let is_last = index == tokens.len() - 1;
+        let parts = parse_single_token(word, options, is_last)
+            .map(|token| vec![token])
+            .or_else(|| options.language.split_compound(word))
+            .unwrap_or_default();
+        for part in parts {
+            match part {
+                NumToken::Literal(0) | NumToken::Fraction(_) | NumToken::Divisor(_) => {
+                    return None;
+                }
+                NumToken::Multiplier(value) | NumToken::Collective(value)
+                    if largest.is_none_or(|(_, largest)| value > largest) =>
+                {
+                    largest = Some((index, value));
+                }
+                _ => {}
+            }
+        }
+    }
+    largest
+}
+
+/// Parses a number from tokens that have already been split up.
+///
+/// The indices in the returned errors refer to the given tokens.
+///
+/// # Errors
+///
+/// Returns an error if the tokens are not a valid number or if the number does
+/// not fit into an `i128`.
+pub fn parse_tokens(tokens: &[Token], options: Options) -> Result<Number, ParseError> {
+    if options.roman {
+        return roman::parse_tokens(tokens);
+    }
+    if options.hex {
+        return hex::parse_tokens(tokens, options);
+    }
+    if options.arith {
+        if let Some(result) = arith::parse_expression(tokens, options)? {
+            return Ok(result);
+        }
+    }
+    #[cfg(feature = "float")]
+    if options.fractions && options.language == Language::English {
+        if let Some(result) = float::parse_named_fraction(tokens, options)? {
+            return Ok(result);
+        }
+    }
+
+    let mut num_tokens = parse_num_tokens(tokens, options)?;
+    let has_number = num_tokens.iter().any(|x| {
+        matches!(
+            x,
+            NumToken::Literal(_) | NumToken::Multiplier(_) | NumToken::Collective(_)
+        )
+    });
+    if !has_number {
+        return Err(ParseError::Empty);
+    }
+    let fraction = float::split_fraction(&mut num_tokens)?;
+    #[cfg(not(feature = "float"))]
+    if fraction.is_some() {
+        return Err(float::reject_fraction(tokens));
+    }
+    if options.language == Language::English && !options.digits {
+        match options.dialect {
+            Dialect::American => {}
+            Dialect::British => lang::en::check_british_and(&num_tokens)?,
+            Dialect::Detect => lang::en::check_consistent_and(&num_tokens)?,
+        }
+    }
+    num_tokens.retain(|x| !matches!(x, NumToken::And));
+    if options.strict && options.language == Language::English && !options.digits {
+        lang::en::check_strict_sequence(&num_tokens)?;
+    }
+    let sign = get_sign(&mut num_tokens);
+    let divisor = get_divisor(&mut num_tokens)?;
+    fold_repeated_multipliers(&mut num_tokens, options.folksy)?;
+
+    // Add the implicit 1 at the start for number strings that start with
+    // a multiplier, like "hundred fifity two". Multipliers that can't stand
+    // on their own in the chosen language were already rejected above.
+    if let Some(NumToken::Multiplier(_) | NumToken::Collective(_)) = num_tokens.first() {
+        num_tokens.insert(0, NumToken::Literal(1));
+    }
+
+    // Generate the number
+    let mut sum = if options.digits {
+        concat_digit_tokens(&num_tokens)?
+    } else if options.language.uses_myriads() {
+        sum_myriad_tokens(&num_tokens)?
+    } else {
+        sum_tokens(&num_tokens)?
+    };
+
+    // Move the whole part of the fraction into the sum. The number stays
+    // fractional even if nothing remains, like "four halves".
+    let fraction = match fraction {
+        Some(fraction) => {
+            sum = sum
+                .checked_add(fraction.numerator / fraction.denominator)
+                .ok_or(ParseError::Overflow)?;
+            Some(Fraction {
+                numerator: fraction.numerator % fraction.denominator,
+                denominator: fraction.denominator,
+            })
+        }
+        None => None,
+    };
+
+    if matches!(sign, Sign::Negative) {
+        sum = -sum;
+    }
+
+    if let Some(divisor) = divisor {
+        if sum % divisor != 0 || fraction.is_some() {
+            return Err(ParseError::NotWhole);
+        }
+        sum /= divisor;
+    }
+
+    Ok(Number {
+        sign,
+        value: sum,
+        fraction,
+    })
+}
+
+fn sum_tokens(num_tokens: &[NumToken]) -> Result<NumType, ParseError> {
+    let mut sum: NumType = 0;
+    let mut acc: NumType = 0;
+    let mut counted = false;
+    for (i, num_token) in num_tokens.iter().enumerate() {
+        match num_token {
+            NumToken::Literal(value) => {
+                acc = acc.checked_add(*value).ok_or(ParseError::Overflow)?;
+                counted = true;
+            }
+            NumToken::Multiplier(value) | NumToken::Collective(value) => {
+                // A multiplier without a count counts once, like the "bin" in
+                // the Turkish "iki bin yüz"
+                if !counted {
+                    acc = 1;
+                }
+                acc = acc.checked_mul(*value).ok_or(ParseError::Overflow)?;
+                counted = true;
+                if !num_tokens
+                    .iter()
+                    .skip(i + 1)
+                    .any(|x| is_larger_multiplier(*x, *value))
+                {
+                    sum = sum.checked_add(acc).ok_or(ParseError::Overflow)?;
+                    acc = 0;
+                    counted = false;
+                }
+            }
+
+            // Any subsequent signs are invalid and should be ignored.
+            // We should never get here anyways, because parse_num_tokens is going to return an error in this case.
+            // Connectors, fractions and divisors have already been removed above.
+            NumToken::Sign(_)
+            | NumToken::And
+            | NumToken::Fraction(_)
+            | NumToken::Divisor(_)
+            | NumToken::Point => {}
+        }
+    }
+
+    sum.checked_add(acc).ok_or(ParseError::Overflow)
+}
+
+/// Sums up the tokens of languages that group digits in fours, like Korean.
+///
+/// Each group of up to four digits is built from the digits and the small
+/// multipliers (ten, hundred and thousand) and then flushed by a multiplier
+/// of ten thousand or larger.
+fn sum_myriad_tokens(num_tokens: &[NumToken]) -> Result<NumType, ParseError> {
+    const MYRIAD: NumType = 10_000;
+
+    let mut sum: NumType = 0;
+    let mut group: NumType = 0;
+    let mut digit: Option<NumType> = None;
+    for num_token in num_tokens {
+        match num_token {
+            NumToken::Literal(value) => {
+                digit = Some(
+                    digit
+                        .unwrap_or(0)
+                        .checked_add(*value)
+                        .ok_or(ParseError::Overflow)?,
+                );
+            }
+
+            // A bare multiplier counts once, like "sip" for ten
+            NumToken::Multiplier(value) if *value < MYRIAD => {
+                group = digit
+                    .take()
+                    .unwrap_or(1)
+                    .checked_mul(*value)
+                    .and_then(|x| group.checked_add(x))
+                    .ok_or(ParseError::Overflow)?;
+            }
+            NumToken::Multiplier(value) => {
+                group = group
+                    .checked_add(digit.take().unwrap_or(0))
+                    .ok_or(ParseError::Overflow)?;
+                let group_value = if group == 0 { 1 } else { group };
+                sum = group_value
+                    .checked_mul(*value)
+                    .and_then(|x| sum.checked_add(x))
+                    .ok_or(ParseError::Overflow)?;
+                group = 0;
+            }
+
+            // Count nouns and the decimal point are only part of the English vocabulary
+            NumToken::Sign(_)
+            | NumToken::And
+            | NumToken::Fraction(_)
+            | NumToken::Divisor(_)
+            | NumToken::Collective(_)
+            | NumToken::Point => {}
+        }
+    }
+
+    group
+        .checked_add(digit.unwrap_or(0))
+        .and_then(|x| sum.checked_add(x))
+        .ok_or(ParseError::Overflow)
+}
+
+/// Reads the tokens as a sequence of digits, like "nineteen oh five" for 1905.
+///
+/// Every number contributes all of its digits, but a tens word followed by a
+/// single digit forms one group, like the "twenty one" in "nineteen twenty one".
+fn concat_digit_tokens(num_tokens: &[NumToken]) -> Result<NumType, ParseError> {
+    let mut digits: NumType = 0;
+    let mut open_tens = false;
+    for num_token in num_tokens {
+        match *num_token {
+            NumToken::Literal(value) if open_tens && (1..10).contains(&value) => {
+                digits = digits.checked_add(value).ok_or(ParseError::Overflow)?;
+                open_tens = false;
+            }
+            NumToken::Literal(value) => {
+                let digit_count = value.checked_ilog10().map_or(1, |x| x + 1);
+                digits = NumType::checked_pow(10, digit_count)
+                    .and_then(|x| digits.checked_mul(x))
+                    .and_then(|x| x.checked_add(value))
+                    .ok_or(ParseError::Overflow)?;
+                open_tens = (20..100).contains(&value) && value % 10 == 0;
+            }
+            NumToken::Multiplier(_) | NumToken::Collective(_) => {
+                return Err(ParseError::UnexpectedMultiplier);
+            }
+
+            NumToken::Sign(_)
+            | NumToken::And
+            | NumToken::Fraction(_)
+            | NumToken::Divisor(_)
+            | NumToken::Point => {}
+        }
+    }
+
+    Ok(digits)
+}
+
+fn parse_num_tokens(tokens: &[Token], options: Options) -> Result<Vec<NumToken>, ParseError> {
+    let mut num_tokens = Vec::with_capacity(tokens.len());
+
+    let mut first = true;
+    let mut skip = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        if skip > 0 {
+            skip -= 1;
+            continue;
+        }
+
+        let is_last = index == tokens.len() - 1;
+
+        // Loose mode reads pasted fullwidth digits and keycap emoji just like
+        // plain digits
+        let normalized = if options.loose {
+            literal::normalize_digits(token_text(*token))
+        } else {
+            None
+        };
+        let token = normalized.as_deref().map_or(*token, Token::Literal);
+
+        match token {
+            Token::Word(word) => {
+                // Localized ordinals are read as the cardinal they are formed
+                // from, like the German "dritte" as "drei"
+                let cardinal = if options.ordinal && is_last {
+                    options.language.ordinal_to_cardinal(word)
+                } else {
+                    None
+                };
+                let word = cardinal.as_deref().unwrap_or(word);
+
+                // Phrases of several words like the French "quatre vingts" take
+                // precedence over their single words
+                if let Some((len, parsed_token)) = options.language.parse_phrase(&tokens[index..]) {
+                    num_tokens.push(parsed_token);
+                    first = false;
+                    skip = len - 1;
+                    continue;
+                }
+
+                let Some(parsed_token) = parse_single_token(word, options, is_last) else {
+                    // Compounds like the German "einundzwanzig" are made up of several words
+                    if let Some(parts) = options.language.split_compound(word) {
+                        num_tokens.extend(parts);
+                        first = false;
+                        continue;
+                    }
+
+                    // Made up words like "gazillion" get their own error, since they
+                    // look like number words but have no value
+                    if let Some(word) = lang::en::fictional_word(word) {
+                        return Err(ParseError::FictionalWord { index, word });
+                    }
+
+                    // Loose mode allows a single trailing word after the number, like a unit,
+                    // and a qualifier like "approximately" before it
+                    if options.loose && !first && is_last {
+                        break;
+                    }
+                    if options.loose && index == 0 && lang::en::is_qualifier(word) {
+                        continue;
+                    }
+
+                    // An article is only expected before an ordinal like "the forty second"
+                    if index == 0
+                        && word.eq_ignore_ascii_case("the")
+                        && (options.loose || ends_in_ordinal(tokens, options))
+                    {
+                        continue;
+                    }
+                    return Err(ParseError::InvalidWord { index });
+                };
+
+                // Error if we encounter a sign that is not in the first position
+                if matches!(parsed_token, NumToken::Sign(_)) && !first {
+                    return Err(ParseError::UnexpectedSign { index });
+                }
+
+                // A number can only have a single decimal point
+                if matches!(parsed_token, NumToken::Point)
+                    && num_tokens.iter().any(|x| matches!(x, NumToken::Point))
+                {
+                    return Err(ParseError::MultiplePoints { index });
+                }
+
+                // A multiplier at the start only counts once if the language allows it
+                if matches!(parsed_token, NumToken::Multiplier(_))
+                    && !num_tokens.iter().any(|x| {
+                        matches!(
+                            x,
+                            NumToken::Literal(_)
+                                | NumToken::Multiplier(_)
+                                | NumToken::Collective(_)
+                        )
+                    })
+                    && !options.language.counts_once(word)
+                {
+                    return Err(ParseError::InvalidWord { index });
+                }
+
+                num_tokens.push(parsed_token);
+                first &= matches!(parsed_token, NumToken::And);
+            }
+
+            Token::Literal(literal) => {
+                push_literal(literal, index, is_last, options, &mut num_tokens)?;
+                first = false;
+            }
+        }
+    }
+
+    Ok(num_tokens)
+}
+
+/// Reads a number literal that is given in place of the words.
+///
+/// A literal can not be mixed with words, except for integers in loose mode,
+/// which take the place of single words like in "2 hundred".
+fn push_literal(
+    literal: &str,
+    index: usize,
+    is_last: bool,
+    options: Options,
+    num_tokens: &mut Vec<NumToken>,
+) -> Result<(), ParseError> {
+    let has_words = num_tokens
+        .iter()
+        .any(|x| !matches!(x, NumToken::Sign(_) | NumToken::And));
+    let mixed = has_words || !is_last;
+    if mixed && !options.loose {
+        return Err(ParseError::MixedLiteral { index });
+    }
+
+    match literal::parse_literal(literal, index)? {
+        NumLiteral::Integer(value) => num_tokens.push(NumToken::Literal(value)),
+        NumLiteral::Decimal { .. } if mixed => {
+            return Err(ParseError::MixedLiteral { index });
+        }
+
+        // A decimal is read just like "three and twenty five hundredths"
+        NumLiteral::Decimal {
+            whole,
+            numerator,
+            denominator,
+        } => num_tokens.extend([
+            NumToken::Literal(whole),
+            NumToken::And,
+            NumToken::Literal(numerator),
+            NumToken::Fraction(denominator),
+        ]),
+    }
+    Ok(())
+}
+
+fn ends_in_ordinal(tokens: &[Token], options: Options) -> bool {
+    options.ordinal
+        && matches!(tokens.last(), Some(Token::Word(word)) if lang::en::is_ordinal(word))
+}
+
+fn parse_single_token(word: &str, options: Options, is_last: bool) -> Option<NumToken> {
+    // Only allocate for words that are not lowercase already. The Unicode-aware
+    // conversion is only needed for the non-ASCII words of the localized modes.
+    let word = if word.is_ascii() {
+        if word.bytes().any(|x| x.is_ascii_uppercase()) {
+            Cow::Owned(word.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(word)
+        }
+    } else {
+        Cow::Owned(word.to_lowercase())
+    };
+
+    options
+        .language
+        .parse_sign(&word)
+        .map(NumToken::Sign)
+        .or_else(|| {
+            options
+                .approx
+                .then(|| lang::en::parse_approx_word(&word))
+                .flatten()
+        })
+        .or_else(|| {
+            options
+                .digits
+                .then(|| lang::en::parse_digit_word(&word))
+                .flatten()
+        })
+        .or_else(|| {
+            options
+                .alias
+                .then(|| lang::en::parse_alias_word(&word))
+                .flatten()
+        })
+        .or_else(|| {
+            options
+                .prog
+                .then(|| lang::en::parse_prog_word(&word))
+                .flatten()
+        })
+        .or_else(|| {
+            // Only the last word of a number takes the ordinal form
+            (options.ordinal && is_last)
+                .then(|| lang::en::parse_ordinal_word(&word))
+                .flatten()
+        })
+        // The connector like "and" is part of each language's own words
+        .or_else(|| options.language.parse_word(&word))
+        .and_then(|token| match token {
+            NumToken::Multiplier(value)
+                if options.language == Language::English && options.scale == Scale::Long =>
+            {
+                lang::en::long_scale(value).map(NumToken::Multiplier)
+            }
+            _ => Some(token),
+        })
+}
+
+fn get_sign(num_tokens: &mut Vec<NumToken>) -> Sign {
+    if let Some(NumToken::Sign(sign)) = num_tokens.first().copied() {
+        num_tokens.remove(0);
+        sign
+    } else {
+        Sign::Unspecified
+    }
+}
+
+fn get_divisor(num_tokens: &mut Vec<NumToken>) -> Result<Option<NumType>, ParseError> {
+    let divisor = if let Some(NumToken::Divisor(divisor)) = num_tokens.first().copied() {
+        num_tokens.remove(0);
+        Some(divisor)
+    } else {
+        None
+    };
+
+    // A divisor always applies to the whole number, so it has to come first
+    if num_tokens.iter().any(|x| matches!(x, NumToken::Divisor(_))) {
+        return Err(ParseError::UnexpectedDivisor);
+    }
+    Ok(divisor)
+}
+
+/// Multiplies runs of the same multiplier, like "thousand thousand" for a
+/// million, and runs of count nouns, like "dozen score" for 240. Without
+/// folding, repeating a multiplier or putting two count nouns in a row is an
+/// error.
+fn fold_repeated_multipliers(num_tokens: &mut Vec<NumToken>, fold: bool) -> Result<(), ParseError> {
+    let mut folded = Vec::with_capacity(num_tokens.len());
+    let mut run_base = None;
+    for num_token in num_tokens.drain(..) {
+        match (num_token, folded.last_mut(), run_base) {
+            (NumToken::Multiplier(value), Some(NumToken::Multiplier(last)), Some(base))
+                if value == base =>
+            {
+                if !fold {
+                    return Err(ParseError::RepeatedMultiplier);
+                }
+                *last = last.checked_mul(value).ok_or(ParseError::Overflow)?;
+            }
+            (NumToken::Multiplier(value), ..) => {
+                run_base = Some(value);
+                folded.push(num_token);
+            }
+            (NumToken::Collective(value), Some(NumToken::Collective(last)), _) if fold => {
+                *last = last.checked_mul(value).ok_or(ParseError::Overflow)?;
+            }
+            (NumToken::Collective(_), Some(NumToken::Collective(_)), _) => {
+                return Err(ParseError::RepeatedMultiplier);
+            }
+            _ => {
+                run_base = None;
+                folded.push(num_token);
+            }
+        }
+    }
+
+    *num_tokens = folded;
+    Ok(())
+}
+
+const fn is_larger_multiplier(x: NumToken, than: NumType) -> bool {
+    if let NumToken::Multiplier(value) | NumToken::Collective(value) = x {
+        value > than
+    } else {
+        false
+    }
+}
+
+/// Options that change how numbers are parsed
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// The language of the number words
+    pub language: Language,
+
+    /// The variant of English, which decides whether "and" is required
+    pub dialect: Dialect,
+
+    /// The scale of the English multipliers above a million, which decides
+    /// whether a "billion" is a thousand millions or a million millions
+    pub scale: Scale,
+
+    /// Ignore a single trailing word like a unit, and read fullwidth digits
+    /// and keycap emoji like plain digits
+    pub loose: bool,
+
+    /// Allow vague quantities like "a few"
+    pub approx: bool,
+
+    /// Read the words as a sequence of digits, like a year
+    pub digits: bool,
+
+    /// Multiply repeated multipliers, like "thousand thousand" for a million
+    pub folksy: bool,
+
+    /// Allow arithmetic on whole numbers, like "two plus three times four"
+    pub arith: bool,
+
+    /// Allow the number to end in an ordinal, like "forty second"
+    pub ordinal: bool,
+
+    /// Allow abbreviated multipliers, like "k" for a thousand
+    pub alias: bool,
+
+    /// Allow the words programmers use for zero, like "null" and "nil"
+    pub prog: bool,
+
+    /// Reject English number words that are just summed up, like the "three"
+    /// in "forty two three"
+    pub strict: bool,
+
+    /// Read a Roman numeral like "MCMLXXXIV" instead of number words
+    pub roman: bool,
+
+    /// Read the words as a sequence of hexadecimal digits, like "f f"
+    pub hex: bool,
+
+    /// Allow English fractions named by their denominator, like "three whole
+    /// five tenths"
+    pub fractions: bool,
+}
+
+/// A token of the input, either a word or a number literal
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// A word like "forty"
+    Word(&'a str),
+
+    /// The text of a literal like `42` or `3.25`
+    Literal(&'a str),
+}
+
+/// A parsed number
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Number {
+    /// The sign the number was explicitly written with
+    pub sign: Sign,
+
+    /// The whole part of the number
+    pub value: i128,
+
+    /// The fractional part of the number, if there is one
+    pub fraction: Option<Fraction>,
+}
+
+impl Number {
+    /// Returns the value of a whole number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::NotWhole`] if the number has a fractional part.
+    pub const fn integer(self) -> Result<i128, ParseError> {
+        if self.fraction.is_some() {
+            Err(ParseError::NotWhole)
+        } else {
+            Ok(self.value)
+        }
+    }
+}
+
+/// Formats the number in decimal notation, like `-3.25`
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(fraction) = self.fraction else {
+            return write!(f, "{}", self.value);
+        };
+
+        // The whole part may be zero, so the sign has to be written separately
+        if matches!(self.sign, Sign::Negative) {
+            f.write_str("-")?;
+        }
+        write!(f, "{}.", self.value.unsigned_abs())?;
+        fraction.fmt_decimals(f)
+    }
+}
+
+/// The sign a number was written with
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sign {
+    /// No sign word was given
+    Unspecified,
+
+    /// The number was explicitly marked positive with "plus" or "positive"
+    Positive,
+
+    /// The number was marked negative with "minus" or "negative"
+    Negative,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum NumToken {
+    Literal(NumType),
+    Multiplier(NumType),
+
+    /// A count noun like "dozen", which multiplies just like "hundred" does.
+    /// A fraction after it is a fraction of the noun, so "a dozen and a half"
+    /// is the integer eighteen.
+    Collective(NumType),
+
+    Sign(Sign),
+    And,
+
+    /// A fraction with the given denominator that is applied to the count
+    /// before it, like "three quarters"
+    Fraction(NumType),
+
+    /// Divides the whole number, like "half of"
+    Divisor(NumType),
+
+    /// The decimal point before the digits of a fraction, like in "three
+    /// point one four"
+    #[cfg_attr(not(feature = "float"), allow(dead_code))]
+    Point,
+}
@@ -0,0 +1,85 @@
+use crate::{NumType, ParseError};
+
+/// A numeric literal that was given instead of words
+#[derive(Debug, Copy, Clone)]
+pub enum NumLiteral {
+    Integer(NumType),
+
+    /// A decimal float literal like `3.25`, split into its whole part and
+    /// the fraction `numerator / denominator`.
+    #[cfg_attr(not(feature = "float"), allow(dead_code))]
+    Decimal {
+        whole: NumType,
+        numerator: NumType,
+        denominator: NumType,
+    },
+}
+
+/// Converts fullwidth digits like "４２" and keycap emoji like "4️⃣" into
+/// plain ASCII digits.
+///
+/// Returns `None` if the text contains anything other than digits, or if it
+/// only contains plain digits already.
+pub fn normalize_digits(text: &str) -> Option<String> {
+    let mut digits = String::with_capacity(text.len());
+    let mut changed = false;
+    for c in text.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            '０'..='９' => {
+                digits.extend(char::from_digit(u32::from(c) - u32::from('０'), 10));
+                changed = true;
+            }
+
+            // A keycap is a plain digit followed by a variation selector and
+            // the combining enclosing keycap
+            '\u{fe0f}' | '\u{20e3}' => changed = true,
+            _ => return None,
+        }
+    }
+    (changed && !digits.is_empty()).then_some(digits)
+}
+
+/// Parses the text of a Rust integer or decimal literal like `0xff` or `3.25`
+pub fn parse_literal(literal: &str, index: usize) -> Result<NumLiteral, ParseError> {
+    let invalid = || ParseError::InvalidLiteral { index };
+
+    let text = literal.replace('_', "");
+    let (digits, radix) = match text.get(..2) {
+        Some("0x") => (&text[2..], 16),
+        Some("0o") => (&text[2..], 8),
+        Some("0b") => (&text[2..], 2),
+        _ => (text.as_str(), 10),
+    };
+
+    // Integer literals with a suffix are rejected here as well, since the type
+    // is determined by the caller
+    if radix != 10 || digits.bytes().all(|x| x.is_ascii_digit()) {
+        return NumType::from_str_radix(digits, radix)
+            .map(NumLiteral::Integer)
+            .map_err(|_| invalid());
+    }
+
+    // Only plain decimal notation is supported for floats
+    let (whole, decimals) = digits.split_once('.').ok_or_else(invalid)?;
+    if whole.is_empty()
+        || decimals.is_empty()
+        || !whole
+            .bytes()
+            .chain(decimals.bytes())
+            .all(|x| x.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    if cfg!(not(feature = "float")) {
+        return Err(ParseError::FloatLiteral { index });
+    }
+
+    let digit_count = decimals.len().try_into().map_err(|_| invalid())?;
+    Ok(NumLiteral::Decimal {
+        whole: whole.parse().map_err(|_| invalid())?,
+        numerator: decimals.parse().map_err(|_| invalid())?,
+        denominator: NumType::checked_pow(10, digit_count).ok_or_else(invalid)?,
+    })
+}
@@ -0,0 +1,55 @@
+use crate::{NumType, Number, ParseError, Sign, Token};
+
+const NUMERALS: [(NumType, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Reads a Roman numeral like "MCMLXXXIV".
+///
+/// Only the canonical form of each number is accepted, so "IIII" and "VV" are
+/// rejected. This is checked by writing the value back out and comparing.
+fn parse_numeral(word: &str) -> Option<NumType> {
+    let word = word.to_ascii_uppercase();
+    let mut rest = word.as_str();
+    let mut value = 0;
+    while !rest.is_empty() {
+        let (digit, text) = NUMERALS.iter().find(|(_, text)| rest.starts_with(text))?;
+        value += digit;
+        rest = &rest[text.len()..];
+    }
+
+    let mut canonical = String::new();
+    let mut remaining = value;
+    for (digit, text) in NUMERALS {
+        while remaining >= digit {
+            canonical.push_str(text);
+            remaining -= digit;
+        }
+    }
+    (canonical == word && value < 4000).then_some(value)
+}
+
+/// Parses the tokens as a single Roman numeral
+pub fn parse_tokens(tokens: &[Token]) -> Result<Number, ParseError> {
+    let [Token::Word(word)] = tokens else {
+        return Err(ParseError::InvalidRoman);
+    };
+    let value = parse_numeral(word).ok_or(ParseError::InvalidRoman)?;
+    Ok(Number {
+        sign: Sign::Unspecified,
+        value,
+        fraction: None,
+    })
+}